@@ -2,73 +2,1145 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use std::io::{self, BufWriter, IsTerminal, Write};
+use std::{
+    fs::{self, File},
+    io::{self, BufRead, BufReader, BufWriter, IsTerminal, Write},
+    path::Path,
+};
 
-use anyhow::Context;
+use anyhow::{Context, bail};
+#[cfg(feature = "base64")]
+use base64::Engine;
+#[cfg(feature = "bech32")]
+use bech32::{Bech32, Bech32m, Checksum};
 use clap::Parser;
+#[cfg(feature = "bech32")]
+use clap::{CommandFactory, error::ErrorKind};
 use indicatif::{ProgressBar, ProgressFinish, ProgressStyle};
+use rand_core::RngCore;
 
 use crate::{
-    cli::{Format, Opt},
+    bigint::{self, BigUint},
+    cards,
+    choose::{self, AliasTable},
+    cli::{
+        BigIntFormat, BigintOpt, BitGroup, BitOrder, ChooseOpt, Command, DatasetFormat, DatasetOpt,
+        DealOpt, Endian, FloatType, Format, NanoidOpt, NetCommand, NetOpt, Opt, PassphraseOpt,
+        PasswordOpt, PermuteOpt, PrimeOpt, ResultFormat, RollOpt, SampleOpt, ShuffleOpt, UlidOpt,
+        UuidOpt, UuidVersion, Wordlist, value_name,
+    },
+    clock::{self, Clock},
+    dataset::Schema,
+    dist::Dist,
+    id::{self, Ulid},
+    int::{Int, IntRange},
+    literal::{Language, Literal},
+    net::MacAddress,
+    passphrase,
+    password::{self, CharClass, Policy},
+    permutation::{self, Permutation, RangePermutation},
+    pronounceable::{PgpWords, Proquint},
+    radix::{Binary, Octal},
+    regex::Regex,
     rng::Rng,
+    sample::{self, Reservoir, WeightedReservoir},
+    shuffle::{self, Shuffler},
+    template::Template,
+    unique,
+    uuid::Uuid,
+};
+#[cfg(feature = "base64")]
+use crate::{
+    cli::{KeyOpt, KeyType},
+    key::{self, Jwk},
 };
 
 // The template string for the progress bar.
 const TEMPLATE: &str = "{spinner:.green} [{elapsed_precise}] {wide_bar:.cyan/blue} {percent}% \
                         {binary_bytes}/{binary_total_bytes} ({binary_bytes_per_sec}, ETA {eta})";
 
+// The template string for the progress bar when the length of the output is
+// unknown.
+const SPINNER_TEMPLATE: &str =
+    "{spinner:.green} [{elapsed_precise}] {binary_bytes} ({binary_bytes_per_sec})";
+
 // 1 MiB.
 const BUF_SIZE: usize = 1 << 20;
 
 // 8 KiB.
 const CHUNK_SIZE: usize = 1 << 13;
 
+// The maximum length of a Bech32 string defined in BIP-173.
+#[cfg(feature = "bech32")]
+const BECH32_MAX_LEN: usize = 90;
+
 /// Runs the program and returns the result.
 pub fn run() -> anyhow::Result<()> {
     let opt = Opt::parse();
+    #[cfg(feature = "bech32")]
+    if opt.hrp.is_some() && !matches!(opt.format, Format::Bech32 | Format::Bech32m) {
+        Opt::command()
+            .error(
+                ErrorKind::ArgumentConflict,
+                "the argument '--hrp <HRP>' can only be used with the bech32 and bech32m formats",
+            )
+            .exit();
+    }
 
     if let Some(shell) = opt.generate_completion {
         Opt::print_completion(shell);
         return Ok(());
     }
 
-    let rng = opt.random_number_generator;
+    let rng = &opt.random_number_generator;
     let mut rng = if let Some(seed) = opt.seed {
-        Rng::seed_from_u64(&rng, seed)
+        Rng::seed_from_u64(rng, seed)
     } else {
-        Rng::try_from_os_rng(&rng).context("could not create a new instance of the RNG")?
+        Rng::try_from_os_rng(rng).context("could not create a new instance of the RNG")?
     };
 
-    let mut remaining = opt
-        .length
-        .expect("the number of bytes to generate should be provided")
-        .try_into()?;
-    let output_length = match opt.format {
-        Format::Raw => Some(remaining),
+    if let Some(command) = &opt.command {
+        if opt.unique && !matches!(command, Command::Net(_)) {
+            bail!("--unique cannot be used with subcommands other than net");
+        }
+        return match command {
+            Command::Password(args) => write_passwords(&opt, args, &mut rng),
+            Command::Passphrase(args) => write_passphrases(&opt, args, &mut rng),
+            Command::Uuid(args) => write_uuids(&opt, args, &mut rng),
+            Command::Ulid(args) => write_ulids(&opt, args, &mut rng),
+            Command::Ksuid => write_ksuids(&opt, &mut rng),
+            Command::Nanoid(args) => write_nanoids(&opt, args, &mut rng),
+            Command::Dataset(args) => write_dataset(&opt, args, &mut rng),
+            Command::Shuffle(args) => write_shuffled(&opt, args, &mut rng),
+            Command::Sample(args) => write_sample(&opt, args, &mut rng),
+            Command::Choose(args) => write_choices(&opt, args, &mut rng),
+            Command::Permute(args) => write_permutation(&opt, args, &mut rng),
+            Command::Roll(args) => write_rolls(&opt, args, &mut rng),
+            Command::Deal(args) => write_deals(&opt, args, &mut rng),
+            Command::Flip => write_flips(&opt, &mut rng),
+            Command::Bigint(args) => write_random_big_ints(&opt, args, &mut rng),
+            Command::Prime(args) => write_primes(&opt, args, &mut rng),
+            Command::Net(args) => write_network_ids(&opt, args, &mut rng),
+            #[cfg(feature = "base64")]
+            Command::Key(args) => write_keys(&opt, args, &mut rng),
+        };
+    }
+
+    if let Some(range) = opt.int {
+        return write_random_integers(&opt, &mut rng, range);
+    }
+    if let Some(dist) = opt.dist {
+        return write_random_floats(&opt, &mut rng, dist);
+    }
+    if let Some(regex) = &opt.regex {
+        return write_regex_matches(&opt, &mut rng, regex);
+    }
+    if let Some(template) = &opt.template {
+        return write_templates(&opt, &mut rng, template);
+    }
+    #[cfg(feature = "bip39")]
+    if opt.format == Format::Bip39 {
+        return write_mnemonics(&opt, &mut rng);
+    }
+
+    write_encoded_bytes(&opt, &mut rng)
+}
+
+/// Writes random bytes encoded in the output format to standard output.
+fn write_encoded_bytes(opt: &Opt, rng: &mut Rng) -> anyhow::Result<()> {
+    let remaining = record_len(opt)?;
+    // ISO C does not allow zero-length arrays.
+    if opt.format == Format::C && remaining == 0 {
+        bail!("the c format requires at least 1 byte");
+    }
+    let multibase = multibase_code(opt)?;
+    let literal = new_literal(opt, remaining);
+    let output_length = encoded_len(opt, remaining).context("output is too long")?;
+    #[cfg(feature = "bech32")]
+    if matches!(opt.format, Format::Bech32 | Format::Bech32m) && output_length > BECH32_MAX_LEN {
+        bail!(
+            "Bech32 string should be at most {BECH32_MAX_LEN} characters, but {remaining} bytes \
+             are encoded in {output_length} characters"
+        );
+    }
+    if opt.verbose {
+        eprintln!("Random bytes: {remaining}");
+        eprintln!("Entropy: {} bits", u128::try_from(remaining)? * 8);
+        // The length of the words depends on the bytes.
+        if opt.format == Format::PgpWords {
+            eprintln!("Maximum output length: {output_length}");
+        } else {
+            eprintln!("Output length: {output_length}");
+        }
+    }
+    // Without --count, the bytes are output as is.
+    let (count, delimiter) = opt.count.map_or((1, ""), |count| (count, opt.delimiter()));
+    let output_length = usize::try_from(count)
+        .ok()
+        .and_then(|count| {
+            output_length
+                .checked_add(delimiter.len())?
+                .checked_mul(count)
+        })
+        .context("output is too long")?;
+
+    let stdout = io::stdout().lock();
+
+    let pb = progress_bar(
+        opt.progress && !stdout.is_terminal(),
+        (opt.format != Format::PgpWords)
+            .then(|| u64::try_from(output_length))
+            .transpose()?,
+    )?;
+
+    let writer = BufWriter::with_capacity(BUF_SIZE.min(output_length), stdout);
+    let mut writer = pb.wrap_write(writer);
+
+    let permutation = opt
+        .unique
+        .then(|| record_permutation(rng, count, remaining))
+        .transpose()?;
+    for index in 0..count {
+        let (image, len) = permutation.as_ref().map_or(([u8::MIN; 16], 0), |p| {
+            (p.apply(index.into()).to_be_bytes(), remaining.min(16))
+        });
+        let mut prefix = &image[image.len() - len..];
+        let mut fill = |buf: &mut [u8]| {
+            let len = prefix.len().min(buf.len());
+            let (head, tail) = buf.split_at_mut(len);
+            head.copy_from_slice(&prefix[..len]);
+            prefix = &prefix[len..];
+            rng.fill_bytes(tail);
+        };
+        writer
+            .write_all(multibase.as_bytes())
+            .context("could not write multibase code to standard output")?;
+        writer = write_random_bytes(writer, &mut fill, opt, literal.as_ref(), remaining)?;
+        writer
+            .write_all(delimiter.as_bytes())
+            .context("could not write random bytes to standard output")?;
+    }
+    writer
+        .flush()
+        .context("could not flush random bytes to standard output")?;
+    Ok(())
+}
+
+/// Creates a source code literal for `len` bytes if the output format is a
+/// source code literal.
+fn new_literal(opt: &Opt, len: usize) -> Option<Literal<'_>> {
+    let language = match opt.format {
+        Format::C => Language::C,
+        Format::Go => Language::Go,
+        Format::JavaScript => Language::JavaScript,
+        Format::Python => Language::Python,
+        Format::Rust => Language::Rust,
+        _ => return None,
+    };
+    let name = opt
+        .name
+        .as_deref()
+        .unwrap_or_else(|| language.default_name());
+    Some(Literal::new(language, name, opt.items_per_line, len))
+}
+
+/// Returns the number of bytes of the result of encoding `len` bytes in the
+/// output format.
+///
+/// This includes the multibase code if `--multibase` is specified. Returns
+/// [`None`] if the length overflows.
+fn encoded_len(opt: &Opt, len: usize) -> Option<usize> {
+    let encoded = match opt.format {
+        Format::Raw => Some(len),
         #[cfg(feature = "base64")]
-        Format::Base64 | Format::Base64Url => base64::encoded_len(remaining, true),
+        Format::Base64 | Format::Base64Url => base64::encoded_len(len, true),
         #[cfg(feature = "hex")]
-        Format::Hex => remaining.checked_mul(2),
+        Format::Hex => len.checked_mul(2),
+        Format::Binary => Binary::encoded_len(len, opt.bit_group),
+        Format::Octal => Octal::encoded_len(len),
+        Format::Proquint => Proquint::encoded_len(len),
+        // The length of the words depends on the bytes, so this is the maximum.
+        Format::PgpWords => PgpWords::max_encoded_len(len),
+        Format::C | Format::Go | Format::JavaScript | Format::Python | Format::Rust => {
+            new_literal(opt, len)
+                .as_ref()
+                .and_then(Literal::encoded_len)
+        }
+        #[cfg(feature = "bech32")]
+        Format::Bech32 | Format::Bech32m => {
+            let hrp = opt.hrp.expect("HRP should be provided");
+            // The HRP is followed by the separator, the 5-bit groups of the
+            // data and the checksum.
+            hrp.len()
+                .checked_add(1)?
+                .checked_add(len.checked_mul(8)?.div_ceil(5))?
+                .checked_add(Bech32::CHECKSUM_LENGTH)
+        }
+        // The length of a mnemonic depends on the words.
+        #[cfg(feature = "bip39")]
+        Format::Bip39 => None,
+    };
+    encoded?.checked_add(usize::from(opt.multibase))
+}
+
+/// Returns the multibase code of the output format if `--multibase` is
+/// specified.
+fn multibase_code(opt: &Opt) -> anyhow::Result<&'static str> {
+    if !opt.multibase {
+        return Ok("");
     }
-    .context("output is too long")?;
+    let code = match opt.format {
+        Format::Raw => "\0",
+        #[cfg(feature = "base64")]
+        Format::Base64 => "M",
+        #[cfg(feature = "base64")]
+        Format::Base64Url => "U",
+        #[cfg(feature = "hex")]
+        Format::Hex => "f",
+        Format::Binary if opt.bit_order == BitOrder::Msb && opt.bit_group == BitGroup::None => "0",
+        Format::Octal if opt.bit_order == BitOrder::Msb => "7",
+        Format::Binary | Format::Octal => {
+            bail!("multibase requires `--bit-order msb` and `--bit-group none`")
+        }
+        _ => {
+            let format = value_name(&opt.format);
+            bail!("the {format} format has no multibase code");
+        }
+    };
+    Ok(code)
+}
+
+/// Encodes `data` as a Bech32 or Bech32m string.
+#[cfg(feature = "bech32")]
+fn encode_bech32(opt: &Opt, data: &[u8]) -> String {
+    let hrp = opt.hrp.expect("HRP should be provided");
+    let encoded = if opt.format == Format::Bech32 {
+        bech32::encode::<Bech32>(hrp, data)
+    } else {
+        bech32::encode::<Bech32m>(hrp, data)
+    };
+    encoded.expect("length of Bech32 string should be checked")
+}
+
+/// Returns the number of random bytes of each record.
+fn record_len(opt: &Opt) -> anyhow::Result<usize> {
+    if let Some(bits) = opt.bits {
+        return Ok(bits.div_ceil(8));
+    }
+    if let Some(chars) = opt.output_chars {
+        if opt.format == Format::PgpWords {
+            bail!("--output-chars cannot be used with the pgp-words format");
+        }
+        // Every output format encodes a byte in at least 1 character, so the
+        // number of bytes is at most `chars`.
+        let (mut len, mut high) = (0, chars);
+        while len < high {
+            let mid = len + (high - len) / 2;
+            if encoded_len(opt, mid).is_some_and(|encoded| encoded < chars) {
+                len = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        let encoded = encoded_len(opt, len).context("output is too long")?;
+        if encoded != chars {
+            let format = value_name(&opt.format);
+            let msg =
+                format!("no number of bytes is encoded in exactly {chars} characters in {format}");
+            match len.checked_sub(1).and_then(|len| encoded_len(opt, len)) {
+                Some(shorter) => {
+                    bail!("{msg}; the nearest lengths are {shorter} and {encoded}")
+                }
+                None => bail!("{msg}; the shortest length is {encoded}"),
+            }
+        }
+        return Ok(len);
+    }
+    Ok(opt
+        .length
+        .expect("the number of bytes to generate should be provided")
+        .try_into()?)
+}
+
+/// Creates a random permutation for `count` unique records of `remaining`
+/// bytes.
+///
+/// Each record begins with the image of its index under the permutation, so no
+/// two records are the same.
+fn record_permutation(rng: &mut Rng, count: u64, remaining: usize) -> anyhow::Result<Permutation> {
+    let space = unique::byte_space(remaining);
+    #[allow(clippy::cast_precision_loss)]
+    if count as f64 > space {
+        bail!("cannot generate {count} unique records of {remaining} bytes");
+    }
+    eprintln!(
+        "Collision probability without --unique: {:.2e}",
+        unique::collision_probability(count, space)
+    );
+    let max = if remaining < 16 {
+        (1 << (remaining * 8)) - 1
+    } else {
+        u128::MAX
+    };
+    Ok(Permutation::new(rng, max))
+}
+
+/// Creates a progress bar which shows the progress of the output.
+///
+/// If `len` is [`None`], the progress bar shows only the number of bytes
+/// written.
+fn progress_bar(show: bool, len: Option<u64>) -> anyhow::Result<ProgressBar> {
+    if !show {
+        return Ok(ProgressBar::hidden());
+    }
+    let pb = if let Some(len) = len {
+        ProgressBar::new(len).with_style(ProgressStyle::with_template(TEMPLATE)?)
+    } else {
+        ProgressBar::no_length().with_style(ProgressStyle::with_template(SPINNER_TEMPLATE)?)
+    };
+    Ok(pb.with_finish(ProgressFinish::AndLeave))
+}
+
+/// Creates a random permutation of `range` if `--unique` is specified.
+///
+/// With `--unique`, the items are the images of the indices under the
+/// permutation.
+fn unique_permutation(
+    opt: &Opt,
+    rng: &mut Rng,
+    range: IntRange,
+    items: &str,
+) -> anyhow::Result<Option<Permutation>> {
+    if !opt.unique {
+        return Ok(None);
+    }
+    let count = opt.count();
+    let span = range.span();
+    if count.checked_sub(1).is_some_and(|n| u128::from(n) > span) {
+        bail!("cannot generate {count} unique {items} in the range");
+    }
+    #[allow(clippy::cast_precision_loss)]
+    let space = span as f64 + 1.0;
+    eprintln!(
+        "Collision probability without --unique: {:.2e}",
+        unique::collision_probability(count, space)
+    );
+    Ok(Some(Permutation::new(rng, span)))
+}
+
+/// Writes random integers in `range` to standard output.
+fn write_random_integers(opt: &Opt, rng: &mut Rng, range: IntRange) -> anyhow::Result<()> {
+    let count = opt.count();
+    let permutation = unique_permutation(opt, rng, range, "integers")?;
 
     let stdout = io::stdout().lock();
+    let pb = progress_bar(opt.progress && !stdout.is_terminal(), None)?;
+    let mut writer = pb.wrap_write(BufWriter::with_capacity(BUF_SIZE, stdout));
+    for index in 0..count {
+        let value = permutation
+            .as_ref()
+            .map_or_else(|| range.sample(rng), |p| range.nth(p.apply(index.into())));
+        write!(writer, "{value}{}", opt.delimiter())
+            .context("could not write random integers to standard output")?;
+    }
+    writer
+        .flush()
+        .context("could not flush random integers to standard output")
+}
 
-    let pb = if opt.progress && !stdout.is_terminal() {
-        let style = ProgressStyle::with_template(TEMPLATE)?;
-        ProgressBar::new(u64::try_from(output_length)?)
-            .with_style(style)
-            .with_finish(ProgressFinish::AndLeave)
+/// Writes random network identifiers to standard output.
+fn write_network_ids(opt: &Opt, args: &NetOpt, rng: &mut Rng) -> anyhow::Result<()> {
+    let (range, items) = match &args.command {
+        NetCommand::Mac => (MacAddress::range(), "MAC addresses"),
+        NetCommand::Ip(args) => (args.cidr.offsets(args.hosts_only), "IP addresses"),
+        NetCommand::Port(args) => (args.range, "ports"),
+    };
+    let permutation = unique_permutation(opt, rng, range, items)?;
+
+    let stdout = io::stdout().lock();
+    let pb = progress_bar(opt.progress && !stdout.is_terminal(), None)?;
+    let mut writer = pb.wrap_write(BufWriter::with_capacity(BUF_SIZE, stdout));
+    for index in 0..opt.count() {
+        let value = permutation
+            .as_ref()
+            .map_or_else(|| range.sample(rng), |p| range.nth(p.apply(index.into())));
+        let Int::Unsigned(n) = value else {
+            unreachable!("network identifiers should be unsigned");
+        };
+        let result = match &args.command {
+            NetCommand::Mac => write!(writer, "{}", MacAddress::nth(n)),
+            NetCommand::Ip(args) => write!(writer, "{}", args.cidr.nth(n)),
+            NetCommand::Port(_) => write!(writer, "{n}"),
+        };
+        result
+            .and_then(|()| writer.write_all(opt.delimiter().as_bytes()))
+            .with_context(|| format!("could not write {items} to standard output"))?;
+    }
+    writer
+        .flush()
+        .with_context(|| format!("could not flush {items} to standard output"))
+}
+
+/// Writes random floating-point numbers which follow `dist` to standard
+/// output.
+fn write_random_floats(opt: &Opt, rng: &mut Rng, dist: Dist) -> anyhow::Result<()> {
+    let stdout = io::stdout().lock();
+    let pb = progress_bar(opt.progress && !stdout.is_terminal(), None)?;
+    let mut writer = pb.wrap_write(BufWriter::with_capacity(BUF_SIZE, stdout));
+    for _ in 0..opt.count() {
+        let value = dist.sample(rng);
+        #[allow(clippy::cast_possible_truncation)]
+        let result = match (opt.packed, opt.endian) {
+            (Some(FloatType::F32), Endian::Little) => {
+                writer.write_all(&(value as f32).to_le_bytes())
+            }
+            (Some(FloatType::F32), Endian::Big) => writer.write_all(&(value as f32).to_be_bytes()),
+            (Some(FloatType::F64), Endian::Little) => writer.write_all(&value.to_le_bytes()),
+            (Some(FloatType::F64), Endian::Big) => writer.write_all(&value.to_be_bytes()),
+            (None, _) => {
+                if let Some(precision) = opt.precision {
+                    write!(writer, "{value:.precision$}{}", opt.delimiter())
+                } else {
+                    write!(writer, "{value}{}", opt.delimiter())
+                }
+            }
+        };
+        result.context("could not write random floating-point numbers to standard output")?;
+    }
+    writer
+        .flush()
+        .context("could not flush random floating-point numbers to standard output")
+}
+
+/// Writes random strings which match `regex` to standard output.
+fn write_regex_matches(opt: &Opt, rng: &mut Rng, regex: &Regex) -> anyhow::Result<()> {
+    let stdout = io::stdout().lock();
+    let pb = progress_bar(opt.progress && !stdout.is_terminal(), None)?;
+    let mut writer = pb.wrap_write(BufWriter::with_capacity(BUF_SIZE, stdout));
+    for _ in 0..opt.count() {
+        let s = regex.generate(rng, opt.max_repeat);
+        write!(writer, "{s}{}", opt.delimiter())
+            .context("could not write random strings to standard output")?;
+    }
+    writer
+        .flush()
+        .context("could not flush random strings to standard output")
+}
+
+/// Writes a synthetic dataset which follows the schema to standard output.
+fn write_dataset(opt: &Opt, args: &DatasetOpt, rng: &mut Rng) -> anyhow::Result<()> {
+    let path = &args.schema;
+    let schema =
+        fs::read_to_string(path).with_context(|| format!("could not read {}", path.display()))?;
+    let schema = if path.extension().is_some_and(|ext| ext == "json") {
+        Schema::from_json(&schema)
+    } else {
+        Schema::from_toml(&schema)
+    }
+    .with_context(|| format!("invalid schema {}", path.display()))?;
+
+    let stdout = io::stdout().lock();
+    let pb = progress_bar(opt.progress && !stdout.is_terminal(), None)?;
+    let mut writer = pb.wrap_write(BufWriter::with_capacity(BUF_SIZE, stdout));
+    let mut buf = String::new();
+    if args.format == DatasetFormat::Csv && !args.no_header {
+        schema.write_csv_header(&mut buf);
+    }
+    for _ in 0..opt.count() {
+        match args.format {
+            DatasetFormat::Csv => schema.write_csv_row(rng, &mut buf),
+            DatasetFormat::Ndjson => schema.write_ndjson_row(rng, &mut buf),
+        }
+        writer
+            .write_all(buf.as_bytes())
+            .context("could not write dataset to standard output")?;
+        buf.clear();
+    }
+    writer
+        .write_all(buf.as_bytes())
+        .and_then(|()| writer.flush())
+        .context("could not write dataset to standard output")
+}
+
+/// Writes the shuffled records of the input to standard output.
+fn write_shuffled(opt: &Opt, args: &ShuffleOpt, rng: &mut Rng) -> anyhow::Result<()> {
+    let buffer_size = usize::try_from(args.buffer_size.as_u64()).context("buffer is too large")?;
+    let delimiter = if opt.null { b'\0' } else { b'\n' };
+    let mut shuffler = Shuffler::new(delimiter, buffer_size, args.head_count.unwrap_or(u64::MAX));
+
+    let stdout = io::stdout().lock();
+    let pb = progress_bar(opt.progress && !stdout.is_terminal(), None)?;
+    let mut writer = pb.wrap_write(BufWriter::with_capacity(BUF_SIZE, stdout));
+    let mut write = |record: &[u8]| {
+        writer.write_all(record)?;
+        writer.write_all(opt.delimiter().as_bytes())
+    };
+    shuffler.shuffle(rng, open_input(args.input.as_deref())?, &mut write)?;
+    writer
+        .flush()
+        .context("could not flush shuffled records to standard output")
+}
+
+/// Writes the records sampled from the input to standard output.
+fn write_sample(opt: &Opt, args: &SampleOpt, rng: &mut Rng) -> anyhow::Result<()> {
+    let delimiter = if opt.null { b'\0' } else { b'\n' };
+    let mut reader = open_input(args.input.as_deref())?;
+    let records = if let Some(field) = args.weight_field {
+        let mut reservoir = WeightedReservoir::new(args.size);
+        let mut line = 0;
+        while let Some(record) = shuffle::read_record(&mut reader, delimiter)? {
+            line += 1;
+            let weight =
+                sample::parse_weight(&record, field.into(), args.field_separator.as_deref())
+                    .with_context(|| format!("invalid record {line}"))?;
+            reservoir.push(rng, record, weight);
+        }
+        reservoir.into_records()
     } else {
-        ProgressBar::hidden()
+        let mut reservoir = Reservoir::new(args.size);
+        while let Some(record) = shuffle::read_record(&mut reader, delimiter)? {
+            reservoir.push(rng, record);
+        }
+        reservoir.into_records()
     };
 
-    let writer = BufWriter::with_capacity(BUF_SIZE.min(output_length), stdout);
-    let mut writer = pb.wrap_write(writer);
+    let stdout = io::stdout().lock();
+    let pb = progress_bar(opt.progress && !stdout.is_terminal(), None)?;
+    let mut writer = pb.wrap_write(BufWriter::with_capacity(BUF_SIZE, stdout));
+    for record in records {
+        writer
+            .write_all(&record)
+            .and_then(|()| writer.write_all(opt.delimiter().as_bytes()))
+            .context("could not write sampled records to standard output")?;
+    }
+    writer
+        .flush()
+        .context("could not flush sampled records to standard output")
+}
+
+/// Writes the items chosen at random to standard output.
+fn write_choices(opt: &Opt, args: &ChooseOpt, rng: &mut Rng) -> anyhow::Result<()> {
+    let items = if let Some(path) = &args.file {
+        let items = fs::read_to_string(path)
+            .with_context(|| format!("could not read {}", path.display()))?;
+        items
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(choose::parse_item)
+            .collect::<anyhow::Result<Vec<_>>>()
+            .with_context(|| format!("invalid items {}", path.display()))?
+    } else {
+        args.items
+            .iter()
+            .map(|item| choose::parse_item(item))
+            .collect::<anyhow::Result<Vec<_>>>()?
+    };
+    let weights = items.iter().map(|(_, weight)| *weight).collect::<Vec<_>>();
 
+    let stdout = io::stdout().lock();
+    let pb = progress_bar(opt.progress && !stdout.is_terminal(), None)?;
+    let mut writer = pb.wrap_write(BufWriter::with_capacity(BUF_SIZE, stdout));
+    let write = |index: usize| {
+        write!(writer, "{}{}", items[index].0, opt.delimiter())
+            .context("could not write chosen items to standard output")
+    };
+    let count = opt.count();
+    if args.without_replacement {
+        let count = usize::try_from(count).context("too many items to choose")?;
+        choose::choose_without_replacement(rng, &weights, count)?
+            .into_iter()
+            .try_for_each(write)?;
+    } else {
+        let table = AliasTable::new(&weights)?;
+        (0..count).map(|_| table.sample(rng)).try_for_each(write)?;
+    }
+    writer
+        .flush()
+        .context("could not flush chosen items to standard output")
+}
+
+/// Writes a random permutation of an integer range to standard output.
+fn write_permutation(opt: &Opt, args: &PermuteOpt, rng: &mut Rng) -> anyhow::Result<()> {
+    let permutation = RangePermutation::new(rng, args.len);
+    let stdout = io::stdout().lock();
+    let pb = progress_bar(opt.progress && !stdout.is_terminal(), None)?;
+    let mut writer = pb.wrap_write(BufWriter::with_capacity(BUF_SIZE, stdout));
+    let mut write = |n: u128| {
+        write!(writer, "{n}{}", opt.delimiter())
+            .context("could not write elements to standard output")
+    };
+    if args.index.is_empty() {
+        let count = args.head_count.unwrap_or(args.len).min(args.len);
+        if args.combination {
+            // The elements are sorted in memory.
+            if count > permutation::IN_MEMORY_MAX {
+                bail!(
+                    "--combination can output at most {} elements",
+                    permutation::IN_MEMORY_MAX
+                );
+            }
+            let mut elements = (0..count)
+                .map(|i| permutation.get(i).expect("index should be in range"))
+                .collect::<Vec<_>>();
+            elements.sort_unstable();
+            elements.into_iter().try_for_each(write)?;
+        } else {
+            (0..count)
+                .map(|i| permutation.get(i).expect("index should be in range"))
+                .try_for_each(write)?;
+        }
+    } else {
+        for &index in &args.index {
+            let n = permutation.get(index).with_context(|| {
+                format!("index {index} is out of range for {} elements", args.len)
+            })?;
+            write(n)?;
+        }
+    }
+    writer
+        .flush()
+        .context("could not flush elements to standard output")
+}
+
+/// Writes BIP-39 mnemonics which encode random bytes to standard output.
+#[cfg(feature = "bip39")]
+fn write_mnemonics(opt: &Opt, rng: &mut Rng) -> anyhow::Result<()> {
+    if opt.output_chars.is_some() {
+        bail!("--output-chars cannot be used with the bip39 format");
+    }
+    if opt.unique {
+        bail!("--unique cannot be used with the bip39 format");
+    }
+    if opt.multibase {
+        bail!("--multibase cannot be used with the bip39 format");
+    }
+    let len = record_len(opt)?;
+    if ![16, 20, 24, 28, 32].contains(&len) {
+        bail!(
+            "the bip39 format requires 16, 20, 24, 28 or 32 bytes, but {len} bytes were specified"
+        );
+    }
+    if opt.verbose {
+        eprintln!("Random bytes: {len}");
+        eprintln!("Entropy: {} bits", len * 8);
+        eprintln!("Words: {}", len * 3 / 4);
+    }
+    // Without --count, the mnemonic is output as is.
+    let (count, delimiter) = opt.count.map_or((1, ""), |count| (count, opt.delimiter()));
+
+    let stdout = io::stdout().lock();
+    let pb = progress_bar(opt.progress && !stdout.is_terminal(), None)?;
+    let mut writer = pb.wrap_write(BufWriter::with_capacity(BUF_SIZE, stdout));
+    let mut entropy = [u8::MIN; 32];
+    for _ in 0..count {
+        rng.fill_bytes(&mut entropy[..len]);
+        let mnemonic = bip39::Mnemonic::from_entropy_in(opt.bip39_language.into(), &entropy[..len])
+            .expect("length of entropy should be valid");
+        write!(writer, "{mnemonic}{delimiter}")
+            .context("could not write BIP-39 mnemonics to standard output")?;
+    }
+    writer
+        .flush()
+        .context("could not flush BIP-39 mnemonics to standard output")
+}
+
+/// Writes the results of rolling dice to standard output.
+fn write_rolls(opt: &Opt, args: &RollOpt, rng: &mut Rng) -> anyhow::Result<()> {
+    let stdout = io::stdout().lock();
+    let pb = progress_bar(opt.progress && !stdout.is_terminal(), None)?;
+    let mut writer = pb.wrap_write(BufWriter::with_capacity(BUF_SIZE, stdout));
+    for _ in 0..opt.count() {
+        for expression in &args.expressions {
+            let roll = expression.roll(rng);
+            match args.format {
+                ResultFormat::Text => write!(writer, "{roll}"),
+                ResultFormat::Json => serde_json::to_writer(&mut writer, &roll).map_err(Into::into),
+            }
+            .and_then(|()| write!(writer, "{}", opt.delimiter()))
+            .context("could not write rolls to standard output")?;
+        }
+    }
+    writer
+        .flush()
+        .context("could not flush rolls to standard output")
+}
+
+/// Writes the hands dealt from shuffled decks to standard output.
+fn write_deals(opt: &Opt, args: &DealOpt, rng: &mut Rng) -> anyhow::Result<()> {
+    let stdout = io::stdout().lock();
+    let pb = progress_bar(opt.progress && !stdout.is_terminal(), None)?;
+    let mut writer = pb.wrap_write(BufWriter::with_capacity(BUF_SIZE, stdout));
+    for _ in 0..opt.count() {
+        let hands = cards::deal(rng, args.hands, args.cards)?
+            .into_iter()
+            .map(|hand| hand.iter().map(ToString::to_string).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        match args.format {
+            ResultFormat::Text => hands
+                .iter()
+                .try_for_each(|hand| write!(writer, "{}{}", hand.join(" "), opt.delimiter())),
+            ResultFormat::Json => serde_json::to_writer(&mut writer, &hands)
+                .map_err(Into::into)
+                .and_then(|()| write!(writer, "{}", opt.delimiter())),
+        }
+        .context("could not write cards to standard output")?;
+    }
+    writer
+        .flush()
+        .context("could not flush cards to standard output")
+}
+
+/// Writes random integers of the specified bit length to standard output.
+fn write_random_big_ints(opt: &Opt, args: &BigintOpt, rng: &mut Rng) -> anyhow::Result<()> {
+    warn_insecure(opt);
+    write_big_ints(opt, args.format, rng, |rng| {
+        BigUint::random(rng, args.bits, args.odd)
+    })
+}
+
+/// Writes random probable primes to standard output.
+fn write_primes(opt: &Opt, args: &PrimeOpt, rng: &mut Rng) -> anyhow::Result<()> {
+    warn_insecure(opt);
+    write_big_ints(opt, args.format, rng, |rng| {
+        bigint::random_prime(rng, args.bits, args.rounds, args.safe)
+    })
+}
+
+/// Writes the integers generated by `generate` to standard output.
+fn write_big_ints(
+    opt: &Opt,
+    format: BigIntFormat,
+    rng: &mut Rng,
+    mut generate: impl FnMut(&mut Rng) -> BigUint,
+) -> anyhow::Result<()> {
+    let stdout = io::stdout().lock();
+    let pb = progress_bar(opt.progress && !stdout.is_terminal(), None)?;
+    let mut writer = pb.wrap_write(BufWriter::with_capacity(BUF_SIZE, stdout));
+    for _ in 0..opt.count() {
+        let n = generate(rng);
+        let encoded = match format {
+            BigIntFormat::Decimal => n.to_string(),
+            BigIntFormat::Hex => n.to_hex(),
+            #[cfg(feature = "base64")]
+            BigIntFormat::Base64 => {
+                base64::engine::general_purpose::STANDARD.encode(n.to_be_bytes())
+            }
+        };
+        write!(writer, "{encoded}{}", opt.delimiter())
+            .context("could not write integers to standard output")?;
+    }
+    writer
+        .flush()
+        .context("could not flush integers to standard output")
+}
+
+/// Writes the results of flipping coins to standard output.
+fn write_flips(opt: &Opt, rng: &mut Rng) -> anyhow::Result<()> {
+    let stdout = io::stdout().lock();
+    let pb = progress_bar(opt.progress && !stdout.is_terminal(), None)?;
+    let mut writer = pb.wrap_write(BufWriter::with_capacity(BUF_SIZE, stdout));
+    for _ in 0..opt.count() {
+        let side = if rng.below(2) == 0 { "heads" } else { "tails" };
+        write!(writer, "{side}{}", opt.delimiter())
+            .context("could not write coin flips to standard output")?;
+    }
+    writer
+        .flush()
+        .context("could not flush coin flips to standard output")
+}
+
+/// Opens the input file, or standard input if `path` is [`None`] or "-".
+fn open_input(path: Option<&Path>) -> anyhow::Result<Box<dyn BufRead>> {
+    match path.filter(|&path| path != Path::new("-")) {
+        Some(path) => {
+            let file =
+                File::open(path).with_context(|| format!("could not open {}", path.display()))?;
+            Ok(Box::new(BufReader::new(file)))
+        }
+        None => Ok(Box::new(io::stdin().lock())),
+    }
+}
+
+/// Writes the rendered `template` to standard output.
+fn write_templates(opt: &Opt, rng: &mut Rng, template: &Template) -> anyhow::Result<()> {
+    let stdout = io::stdout().lock();
+    let pb = progress_bar(opt.progress && !stdout.is_terminal(), None)?;
+    let mut writer = pb.wrap_write(BufWriter::with_capacity(BUF_SIZE, stdout));
+    for _ in 0..opt.count() {
+        let s = template.render(rng);
+        write!(writer, "{s}{}", opt.delimiter())
+            .context("could not write rendered templates to standard output")?;
+    }
+    writer
+        .flush()
+        .context("could not flush rendered templates to standard output")
+}
+
+/// Prints a warning if the RNG is not cryptographically secure.
+fn warn_insecure(opt: &Opt) {
+    let rng = &opt.random_number_generator;
+    if !rng.is_cryptographically_secure() {
+        let name = value_name(rng);
+        eprintln!("Warning: {name} is not a cryptographically secure RNG");
+    }
+}
+
+/// Returns an error if the RNG is not cryptographically secure and
+/// `allow_insecure` is `false`.
+fn ensure_csprng(opt: &Opt, allow_insecure: bool) -> anyhow::Result<()> {
+    let rng = &opt.random_number_generator;
+    if !rng.is_cryptographically_secure() && !allow_insecure {
+        let name = value_name(rng);
+        bail!(
+            "{name} is not a cryptographically secure RNG; use --allow-insecure to use it anyway"
+        );
+    }
+    Ok(())
+}
+
+/// Writes symmetric keys in the key format to standard output.
+#[cfg(feature = "base64")]
+fn write_keys(opt: &Opt, args: &KeyOpt, rng: &mut Rng) -> anyhow::Result<()> {
+    ensure_csprng(opt, args.allow_insecure)?;
+    if args.key_type != KeyType::Jwk
+        && (args.alg.is_some() || args.kid.is_some() || args.length.is_some())
+    {
+        bail!("--alg, --kid and --length can only be used with the jwk type");
+    }
+    let len = if args.key_type == KeyType::Jwk {
+        key::jwk_len(args.alg, args.length)?
+    } else {
+        key::X25519_KEY_LEN
+    };
+
+    let stdout = io::stdout().lock();
+    let pb = progress_bar(opt.progress && !stdout.is_terminal(), None)?;
+    let mut writer = pb.wrap_write(BufWriter::with_capacity(BUF_SIZE, stdout));
+    let mut key = vec![u8::MIN; len];
+    for _ in 0..opt.count() {
+        rng.fill_bytes(&mut key);
+        let encoded = match args.key_type {
+            KeyType::Jwk => serde_json::to_string(&Jwk::new(&key, args.alg, args.kid.as_deref()))
+                .context("could not serialize JWK")?,
+            KeyType::WireGuard => key::wireguard(key.as_slice().try_into()?),
+            #[cfg(feature = "bech32")]
+            KeyType::Age => key::age(key.as_slice().try_into()?),
+        };
+        write!(writer, "{encoded}{}", opt.delimiter())
+            .context("could not write keys to standard output")?;
+    }
+    writer
+        .flush()
+        .context("could not flush keys to standard output")
+}
+
+/// Writes passwords which follow the character set policy to standard output.
+fn write_passwords(opt: &Opt, args: &PasswordOpt, rng: &mut Rng) -> anyhow::Result<()> {
+    ensure_csprng(opt, args.allow_insecure)?;
+
+    let is_default = [args.lower, args.upper, args.digit, args.symbol]
+        .iter()
+        .all(Option::is_none)
+        && args.custom.is_none();
+    let mut classes = [
+        ("lowercase", password::LOWER, args.lower),
+        ("uppercase", password::UPPER, args.upper),
+        ("digit", password::DIGIT, args.digit),
+        ("symbol", password::SYMBOL, args.symbol),
+    ]
+    .into_iter()
+    .filter_map(|(name, chars, min)| {
+        let min = if is_default && name != "symbol" {
+            Some(1)
+        } else {
+            min
+        };
+        min.map(|min| CharClass {
+            name,
+            chars: password::chars(chars, args.exclude_ambiguous),
+            min,
+        })
+    })
+    .collect::<Vec<_>>();
+    if let Some(custom) = &args.custom {
+        if let Some(class) = classes
+            .iter()
+            .find(|class| class.chars.iter().any(|&c| custom.contains(c)))
+        {
+            bail!(
+                "custom characters overlap with the {} characters",
+                class.name
+            );
+        }
+        classes.push(CharClass {
+            name: "custom",
+            chars: password::chars(custom, args.exclude_ambiguous),
+            min: args.custom_min,
+        });
+    }
+    let policy = Policy::new(classes, args.length).context("invalid password policy")?;
+    eprintln!("Entropy: {:.2} bits", policy.entropy());
+
+    let stdout = io::stdout().lock();
+    let pb = progress_bar(opt.progress && !stdout.is_terminal(), None)?;
+    let mut writer = pb.wrap_write(BufWriter::with_capacity(BUF_SIZE, stdout));
+    for _ in 0..opt.count() {
+        write!(writer, "{}{}", policy.generate(rng), opt.delimiter())
+            .context("could not write passwords to standard output")?;
+    }
+    writer
+        .flush()
+        .context("could not flush passwords to standard output")
+}
+
+/// Writes passphrases which consist of words picked from the wordlist to
+/// standard output.
+fn write_passphrases(opt: &Opt, args: &PassphraseOpt, rng: &mut Rng) -> anyhow::Result<()> {
+    ensure_csprng(opt, args.allow_insecure)?;
+
+    let words = if let Some(path) = &args.wordlist {
+        let wordlist = fs::read_to_string(path)
+            .with_context(|| format!("could not read {}", path.display()))?;
+        passphrase::parse_wordlist(&wordlist, args.case)
+            .with_context(|| format!("invalid wordlist {}", path.display()))?
+    } else {
+        let wordlist = match args.list {
+            Wordlist::EffLarge => passphrase::EFF_LARGE_WORDLIST,
+            Wordlist::EffShort => passphrase::EFF_SHORT_WORDLIST,
+        };
+        passphrase::parse_wordlist(wordlist, args.case)?
+    };
+    let count = args.entropy.map_or_else(
+        || args.words.get(),
+        |bits| passphrase::Generator::words_for_entropy(bits, words.len()),
+    );
+    let generator = passphrase::Generator::new(words, count, args.separator.clone())
+        .digit(args.digit)
+        .symbol(args.symbol);
+    eprintln!("Entropy: {:.2} bits", generator.entropy());
+
+    let stdout = io::stdout().lock();
+    let pb = progress_bar(opt.progress && !stdout.is_terminal(), None)?;
+    let mut writer = pb.wrap_write(BufWriter::with_capacity(BUF_SIZE, stdout));
+    for _ in 0..opt.count() {
+        write!(writer, "{}{}", generator.generate(rng), opt.delimiter())
+            .context("could not write passphrases to standard output")?;
+    }
+    writer
+        .flush()
+        .context("could not flush passphrases to standard output")
+}
+
+/// Creates a new clock which is deterministic if the random seed is specified.
+fn new_clock(opt: &Opt) -> Clock {
+    let start = opt.clock.or_else(|| opt.seed.map(|_| clock::SEEDED_START));
+    Clock::new(start, opt.clock_step)
+}
+
+/// Writes UUIDs to standard output.
+fn write_uuids(opt: &Opt, args: &UuidOpt, rng: &mut Rng) -> anyhow::Result<()> {
+    if args.payload.is_some() && args.version != UuidVersion::V8 {
+        bail!("--payload can only be used with version 8 UUIDs");
+    }
+    let mut clock = new_clock(opt);
+
+    let stdout = io::stdout().lock();
+    let pb = progress_bar(opt.progress && !stdout.is_terminal(), None)?;
+    let mut writer = pb.wrap_write(BufWriter::with_capacity(BUF_SIZE, stdout));
+    for _ in 0..opt.count() {
+        let uuid = match args.version {
+            UuidVersion::V4 => Uuid::new_v4(rng),
+            UuidVersion::V7 => Uuid::new_v7(rng, clock.now()?)?,
+            UuidVersion::V8 => {
+                Uuid::new_v8(rng, args.payload.as_ref().map_or(&[], |p| p.as_bytes()))
+            }
+        };
+        write!(writer, "{}{}", uuid.encode(args.style), opt.delimiter())
+            .context("could not write UUIDs to standard output")?;
+    }
+    writer
+        .flush()
+        .context("could not flush UUIDs to standard output")
+}
+
+/// Writes ULIDs to standard output.
+fn write_ulids(opt: &Opt, args: &UlidOpt, rng: &mut Rng) -> anyhow::Result<()> {
+    let mut clock = new_clock(opt);
+    let mut ulid = Ulid::new(args.monotonic);
+
+    let stdout = io::stdout().lock();
+    let pb = progress_bar(opt.progress && !stdout.is_terminal(), None)?;
+    let mut writer = pb.wrap_write(BufWriter::with_capacity(BUF_SIZE, stdout));
+    for _ in 0..opt.count() {
+        let id = ulid.generate(rng, clock.now()?)?;
+        write!(writer, "{id}{}", opt.delimiter())
+            .context("could not write ULIDs to standard output")?;
+    }
+    writer
+        .flush()
+        .context("could not flush ULIDs to standard output")
+}
+
+/// Writes KSUIDs to standard output.
+fn write_ksuids(opt: &Opt, rng: &mut Rng) -> anyhow::Result<()> {
+    let mut clock = new_clock(opt);
+
+    let stdout = io::stdout().lock();
+    let pb = progress_bar(opt.progress && !stdout.is_terminal(), None)?;
+    let mut writer = pb.wrap_write(BufWriter::with_capacity(BUF_SIZE, stdout));
+    for _ in 0..opt.count() {
+        let id = id::ksuid(rng, clock.now()?)?;
+        write!(writer, "{id}{}", opt.delimiter())
+            .context("could not write KSUIDs to standard output")?;
+    }
+    writer
+        .flush()
+        .context("could not flush KSUIDs to standard output")
+}
+
+/// Writes Nano IDs to standard output.
+fn write_nanoids(opt: &Opt, args: &NanoidOpt, rng: &mut Rng) -> anyhow::Result<()> {
+    let alphabet = args
+        .alphabet
+        .as_deref()
+        .unwrap_or(id::NANOID_ALPHABET)
+        .chars()
+        .collect::<Vec<_>>();
+    let size = args
+        .bits
+        .map_or(args.size, |bits| id::nanoid_size(bits, alphabet.len()));
+    if opt.verbose {
+        #[allow(clippy::cast_precision_loss)]
+        let entropy = size as f64 * (alphabet.len() as f64).log2();
+        eprintln!("Size: {size}");
+        eprintln!("Entropy: {entropy:.2} bits");
+    }
+
+    let stdout = io::stdout().lock();
+    let pb = progress_bar(opt.progress && !stdout.is_terminal(), None)?;
+    let mut writer = pb.wrap_write(BufWriter::with_capacity(BUF_SIZE, stdout));
+    for _ in 0..opt.count() {
+        let id = id::nanoid(rng, &alphabet, size);
+        write!(writer, "{id}{}", opt.delimiter())
+            .context("could not write Nano IDs to standard output")?;
+    }
+    writer
+        .flush()
+        .context("could not flush Nano IDs to standard output")
+}
+
+/// Writes `remaining` random bytes filled by `fill` encoded in the output
+/// format to `writer`.
+fn write_random_bytes<W: Write>(
+    mut writer: W,
+    fill: &mut dyn FnMut(&mut [u8]),
+    opt: &Opt,
+    literal: Option<&Literal<'_>>,
+    mut remaining: usize,
+) -> anyhow::Result<W> {
     let mut buf = [u8::default(); CHUNK_SIZE];
 
-    match opt.format {
+    match &opt.format {
         #[cfg(feature = "base64")]
         format @ (Format::Base64 | Format::Base64Url) => {
             let engine = match format {
@@ -79,23 +1151,44 @@ pub fn run() -> anyhow::Result<()> {
             let mut writer = base64::write::EncoderWriter::new(writer, &engine);
             while remaining > 0 {
                 let chunk_size = CHUNK_SIZE.min(remaining);
-                rng.fill_bytes(&mut buf[..chunk_size]);
+                fill(&mut buf[..chunk_size]);
                 writer
                     .write_all(&buf[..chunk_size])
                     .context("could not write base64 encoded random bytes to standard output")?;
                 remaining -= chunk_size;
             }
-            let mut writer = writer.finish().context(
-                "could not write remaining base64 encoded random bytes to standard output",
-            )?;
             writer
-                .flush()
-                .context("could not flush base64 encoded random bytes to standard output")?;
+                .finish()
+                .context("could not write remaining base64 encoded random bytes to standard output")
+        }
+        Format::C | Format::Go | Format::JavaScript | Format::Python | Format::Rust => {
+            let literal = literal.expect("source code literal should be created");
+            writer
+                .write_all(literal.header().as_bytes())
+                .context("could not write source code literal to standard output")?;
+            let mut offset = 0;
+            while remaining > 0 {
+                let chunk_size = CHUNK_SIZE.min(remaining);
+                fill(&mut buf[..chunk_size]);
+                writer
+                    .write_all(literal.encode(&buf[..chunk_size], offset).as_bytes())
+                    .context("could not write source code literal to standard output")?;
+                offset += chunk_size;
+                remaining -= chunk_size;
+            }
+            writer
+                .write_all(literal.footer().as_bytes())
+                .context("could not write source code literal to standard output")?;
+            Ok(writer)
         }
         format => {
+            let mut binary = Binary::new(opt.bit_order, opt.bit_group);
+            let mut octal = Octal::new(opt.bit_order);
+            let mut proquint = Proquint::new();
+            let mut pgp_words = PgpWords::new();
             while remaining > 0 {
                 let chunk_size = CHUNK_SIZE.min(remaining);
-                rng.fill_bytes(&mut buf[..chunk_size]);
+                fill(&mut buf[..chunk_size]);
                 match format {
                     Format::Raw => writer
                         .write_all(&buf[..chunk_size])
@@ -107,17 +1200,43 @@ pub fn run() -> anyhow::Result<()> {
                             "could not write hex encoded random bytes to standard output",
                         )?;
                     }
-                    #[cfg(feature = "base64")]
+                    Format::Binary => writer
+                        .write_all(binary.encode(&buf[..chunk_size]).as_bytes())
+                        .context(
+                            "could not write binary encoded random bytes to standard output",
+                        )?,
+                    Format::Octal => writer
+                        .write_all(octal.encode(&buf[..chunk_size]).as_bytes())
+                        .context("could not write octal encoded random bytes to standard output")?,
+                    Format::Proquint => writer
+                        .write_all(proquint.encode(&buf[..chunk_size]).as_bytes())
+                        .context(
+                            "could not write proquint encoded random bytes to standard output",
+                        )?,
+                    // The code length is checked, so the data fits in a chunk.
+                    #[cfg(feature = "bech32")]
+                    Format::Bech32 | Format::Bech32m => writer
+                        .write_all(encode_bech32(opt, &buf[..chunk_size]).as_bytes())
+                        .context("could not write Bech32 string to standard output")?,
+                    Format::PgpWords => writer
+                        .write_all(pgp_words.encode(&buf[..chunk_size]).as_bytes())
+                        .context("could not write PGP words to standard output")?,
                     _ => unreachable!(),
                 }
                 remaining -= chunk_size;
             }
+            // The bits which do not fill a digit or a quint are encoded last.
+            let rest = match format {
+                Format::Octal => octal.finish(),
+                Format::Proquint => proquint.finish(),
+                _ => String::new(),
+            };
             writer
-                .flush()
-                .context("could not flush random bytes to standard output")?;
+                .write_all(rest.as_bytes())
+                .context("could not write remaining encoded random bytes to standard output")?;
+            Ok(writer)
         }
     }
-    Ok(())
 }
 
 #[cfg(test)]
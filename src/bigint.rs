@@ -0,0 +1,500 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::{
+    cmp::Ordering,
+    fmt::{self, Write},
+    sync::LazyLock,
+};
+
+use rand_core::RngCore;
+
+use crate::rng::Rng;
+
+/// The primes which are less than 2^12, which are used for trial division.
+static SMALL_PRIMES: LazyLock<Vec<u64>> = LazyLock::new(|| {
+    const LIMIT: usize = 1 << 12;
+    let mut is_composite = vec![false; LIMIT];
+    let mut primes = Vec::new();
+    for n in 2..LIMIT {
+        if !is_composite[n] {
+            primes.push(u64::try_from(n).expect("prime should fit in `u64`"));
+            for m in (n * n..LIMIT).step_by(n) {
+                is_composite[m] = true;
+            }
+        }
+    }
+    primes
+});
+
+/// An unsigned integer of arbitrary size.
+///
+/// The limbs are stored in little-endian order, and the number of limbs is
+/// fixed when the integer is created.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BigUint(Vec<u64>);
+
+impl BigUint {
+    /// Returns a random integer of exactly `bits` bits, whose most significant
+    /// bit is set.
+    ///
+    /// If `odd` is `true`, the least significant bit is also set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is 0.
+    pub fn random(rng: &mut Rng, bits: u32, odd: bool) -> Self {
+        assert!(bits > 0, "`bits` should be positive");
+        let len = usize::try_from(bits.div_ceil(u64::BITS)).expect("length should fit in `usize`");
+        let mut limbs = (0..len).map(|_| rng.next_u64()).collect::<Vec<_>>();
+        let top_bits = bits - u64::BITS * (bits.div_ceil(u64::BITS) - 1);
+        let top = &mut limbs[len - 1];
+        *top &= u64::MAX >> (u64::BITS - top_bits);
+        *top |= 1 << (top_bits - 1);
+        if odd {
+            limbs[0] |= 1;
+        }
+        Self(limbs)
+    }
+
+    /// Returns the number of significant bits.
+    #[cfg(any(test, feature = "base64"))]
+    fn bits(&self) -> u32 {
+        self.0.iter().rposition(|&limb| limb != 0).map_or(0, |i| {
+            u32::try_from(i).expect("length should fit in `u32`") * u64::BITS + u64::BITS
+                - self.0[i].leading_zeros()
+        })
+    }
+
+    /// Returns `2 * self + 1`, which has one more bit than `self`.
+    pub fn double_plus_one(&self) -> Self {
+        let mut limbs = self.0.clone();
+        limbs.push(0);
+        shl1(&mut limbs);
+        limbs[0] |= 1;
+        if limbs.len() > 1 && limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        Self(limbs)
+    }
+
+    /// Returns the remainder of the division by `divisor`.
+    fn rem_u64(&self, divisor: u64) -> u64 {
+        self.0.iter().rev().fold(0, |rem, &limb| {
+            let n = (u128::from(rem) << u64::BITS) | u128::from(limb);
+            u64::try_from(n % u128::from(divisor)).expect("remainder should fit in `u64`")
+        })
+    }
+
+    /// Returns the value if it fits in `u64`.
+    fn to_u64(&self) -> Option<u64> {
+        match self.0.iter().rposition(|&limb| limb != 0) {
+            None => Some(0),
+            Some(0) => Some(self.0[0]),
+            Some(_) => None,
+        }
+    }
+
+    /// Returns the big-endian bytes of the integer without leading zeros.
+    #[cfg(feature = "base64")]
+    pub fn to_be_bytes(&self) -> Vec<u8> {
+        let bytes = self
+            .0
+            .iter()
+            .rev()
+            .flat_map(|limb| limb.to_be_bytes())
+            .collect::<Vec<_>>();
+        let len =
+            usize::try_from(self.bits().div_ceil(u8::BITS)).expect("length should fit in `usize`");
+        bytes[bytes.len() - len.max(1)..].to_vec()
+    }
+
+    /// Returns the hexadecimal representation of the integer.
+    pub fn to_hex(&self) -> String {
+        let mut limbs = self.0.iter().rev().skip_while(|&&limb| limb == 0);
+        let Some(top) = limbs.next() else {
+            return "0".into();
+        };
+        let mut s = format!("{top:x}");
+        for limb in limbs {
+            write!(s, "{limb:016x}").expect("writing to `String` should not fail");
+        }
+        s
+    }
+
+    /// Returns `true` if the integer is probably prime.
+    ///
+    /// After trial division by small primes, the Miller-Rabin test is performed
+    /// with `rounds` random bases. The probability that a composite is reported
+    /// as prime is at most 4^(-`rounds`).
+    pub fn is_probable_prime(&self, rng: &mut Rng, rounds: u32) -> bool {
+        let max_small_prime = *SMALL_PRIMES
+            .last()
+            .expect("small primes should not be empty");
+        for &p in SMALL_PRIMES.iter() {
+            if self.rem_u64(p) == 0 {
+                return self.to_u64() == Some(p);
+            }
+        }
+        if self
+            .to_u64()
+            .is_some_and(|n| n < max_small_prime * max_small_prime)
+        {
+            return self.to_u64() > Some(1);
+        }
+
+        let n = &self.0[..=self
+            .0
+            .iter()
+            .rposition(|&limb| limb != 0)
+            .expect("integer should not be zero")];
+        let mont = Montgomery::new(n);
+        let one = mont.one();
+        // The Montgomery form of n - 1 is n - R.
+        let minus_one = sub(n, &one);
+        let mut exponent = n.to_vec();
+        exponent[0] -= 1;
+        let shift = trailing_zeros(&exponent);
+        shr(&mut exponent, shift);
+        let n_minus_one = sub(n, &[1]);
+        let top_mask = u64::MAX >> n[n.len() - 1].leading_zeros();
+        'rounds: for _ in 0..rounds {
+            // A base is chosen uniformly from the range [2, n - 2].
+            let base = loop {
+                let mut base = (0..n.len()).map(|_| rng.next_u64()).collect::<Vec<_>>();
+                base[n.len() - 1] &= top_mask;
+                if cmp(&base, &[2]).is_ge() && cmp(&base, &n_minus_one).is_lt() {
+                    break base;
+                }
+            };
+            let mut x = mont.pow(&mont.to_montgomery(&base), &exponent);
+            if x == one || x == minus_one {
+                continue;
+            }
+            for _ in 1..shift {
+                x = mont.mul(&x, &x);
+                if x == minus_one {
+                    continue 'rounds;
+                }
+            }
+            return false;
+        }
+        true
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The integer is converted to base 10^19, which is the largest power of
+        // 10 which fits in `u64`.
+        const BASE: u64 = 10_000_000_000_000_000_000;
+        let mut limbs = self.0.clone();
+        let mut digits = Vec::new();
+        while limbs.iter().any(|&limb| limb != 0) {
+            let mut rem = 0;
+            for limb in limbs.iter_mut().rev() {
+                let n = (u128::from(rem) << u64::BITS) | u128::from(*limb);
+                *limb = u64::try_from(n / u128::from(BASE)).expect("quotient should fit in `u64`");
+                rem = u64::try_from(n % u128::from(BASE)).expect("remainder should fit in `u64`");
+            }
+            digits.push(rem);
+        }
+        let Some((top, rest)) = digits.split_last() else {
+            return write!(f, "0");
+        };
+        write!(f, "{top}")?;
+        for digit in rest.iter().rev() {
+            write!(f, "{digit:019}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns a random probable prime of exactly `bits` bits.
+///
+/// If `safe` is `true`, the prime p is a safe prime, that is, (p - 1) / 2 is
+/// also prime.
+///
+/// # Panics
+///
+/// Panics if `bits` is less than 2, or if `safe` is `true` and `bits` is less
+/// than 3.
+pub fn random_prime(rng: &mut Rng, bits: u32, rounds: u32, safe: bool) -> BigUint {
+    if !safe {
+        assert!(bits >= 2, "prime should have at least 2 bits");
+        return loop {
+            let candidate = BigUint::random(rng, bits, true);
+            if candidate.is_probable_prime(rng, rounds) {
+                break candidate;
+            }
+        };
+    }
+    assert!(bits >= 3, "safe prime should have at least 3 bits");
+    loop {
+        let q = BigUint::random(rng, bits - 1, true);
+        let p = q.double_plus_one();
+        // Since p = 2q + 1, p is divisible by a small prime r iff q is congruent
+        // to (r - 1) / 2 modulo r, so both are sieved cheaply before the
+        // Miller-Rabin test.
+        let sieved = SMALL_PRIMES.iter().skip(1).any(|&r| {
+            let (q_rem, p_rem) = (q.rem_u64(r), p.rem_u64(r));
+            (q_rem == 0 && q.to_u64() != Some(r)) || (p_rem == 0 && p.to_u64() != Some(r))
+        });
+        if !sieved && q.is_probable_prime(rng, rounds) && p.is_probable_prime(rng, rounds) {
+            return p;
+        }
+    }
+}
+
+/// Arithmetic modulo an odd integer in the Montgomery form.
+struct Montgomery {
+    modulus: Vec<u64>,
+    // -modulus^-1 modulo 2^64.
+    inv: u64,
+    // R^2 modulo the modulus, where R is 2^(64 * the number of limbs).
+    r2: Vec<u64>,
+}
+
+impl Montgomery {
+    fn new(modulus: &[u64]) -> Self {
+        debug_assert!(modulus[0] & 1 == 1, "modulus should be odd");
+        // Newton's method doubles the number of correct bits in each step.
+        let mut inv = 1_u64;
+        for _ in 0..6 {
+            inv = inv.wrapping_mul(2_u64.wrapping_sub(modulus[0].wrapping_mul(inv)));
+        }
+        let mut r2 = vec![0; modulus.len()];
+        r2[0] = 1;
+        for _ in
+            0..2 * u64::BITS * u32::try_from(modulus.len()).expect("length should fit in `u32`")
+        {
+            let carry = r2[r2.len() - 1] >> (u64::BITS - 1);
+            shl1(&mut r2);
+            if carry == 1 || cmp(&r2, modulus).is_ge() {
+                r2 = sub(&r2, modulus);
+            }
+        }
+        Self {
+            modulus: modulus.to_vec(),
+            inv: inv.wrapping_neg(),
+            r2,
+        }
+    }
+
+    fn one(&self) -> Vec<u64> {
+        let mut one = vec![0; self.modulus.len()];
+        one[0] = 1;
+        self.to_montgomery(&one)
+    }
+
+    fn to_montgomery(&self, a: &[u64]) -> Vec<u64> {
+        self.mul(a, &self.r2)
+    }
+
+    /// Returns a * b * R^-1 modulo the modulus using the CIOS method.
+    fn mul(&self, lhs: &[u64], rhs: &[u64]) -> Vec<u64> {
+        let (modulus, len) = (&self.modulus, self.modulus.len());
+        let mut t = vec![0_u64; len + 2];
+        for &limb in rhs {
+            let mut carry = 0;
+            for j in 0..len {
+                (t[j], carry) = mul_add(lhs[j], limb, t[j], carry);
+            }
+            let (sum, overflow) = t[len].overflowing_add(carry);
+            t[len] = sum;
+            t[len + 1] = u64::from(overflow);
+
+            let factor = t[0].wrapping_mul(self.inv);
+            let (_, mut carry) = mul_add(factor, modulus[0], t[0], 0);
+            for j in 1..len {
+                (t[j - 1], carry) = mul_add(factor, modulus[j], t[j], carry);
+            }
+            let (sum, overflow) = t[len].overflowing_add(carry);
+            t[len - 1] = sum;
+            t[len] = t[len + 1] + u64::from(overflow);
+        }
+        let mut result = t[..len].to_vec();
+        if t[len] != 0 || cmp(&result, modulus).is_ge() {
+            result = sub(&result, modulus);
+        }
+        result
+    }
+
+    /// Returns base^exponent in the Montgomery form, where `base` is in the
+    /// Montgomery form.
+    fn pow(&self, base: &[u64], exponent: &[u64]) -> Vec<u64> {
+        let mut result = self.one();
+        for &limb in exponent.iter().rev() {
+            for i in (0..u64::BITS).rev() {
+                result = self.mul(&result, &result);
+                if (limb >> i) & 1 == 1 {
+                    result = self.mul(&result, base);
+                }
+            }
+        }
+        result
+    }
+}
+
+/// Returns `lhs * rhs + addend + carry` as the low and high limbs.
+#[allow(clippy::cast_possible_truncation)]
+const fn mul_add(lhs: u64, rhs: u64, addend: u64, carry: u64) -> (u64, u64) {
+    let result = (lhs as u128) * (rhs as u128) + (addend as u128) + (carry as u128);
+    (result as u64, (result >> u64::BITS) as u64)
+}
+
+/// Compares two integers, where `b` may have fewer limbs than `a`.
+fn cmp(a: &[u64], b: &[u64]) -> Ordering {
+    (0..a.len().max(b.len()))
+        .rev()
+        .map(|i| {
+            a.get(i)
+                .copied()
+                .unwrap_or_default()
+                .cmp(&b.get(i).copied().unwrap_or_default())
+        })
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
+
+/// Returns a - b modulo 2^(64 * the number of limbs of `a`).
+fn sub(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut borrow = false;
+    a.iter()
+        .enumerate()
+        .map(|(i, &a)| {
+            let (diff, overflow1) = a.overflowing_sub(b.get(i).copied().unwrap_or_default());
+            let (diff, overflow2) = diff.overflowing_sub(u64::from(borrow));
+            borrow = overflow1 || overflow2;
+            diff
+        })
+        .collect()
+}
+
+fn shl1(a: &mut [u64]) {
+    let mut carry = 0;
+    for limb in a {
+        (*limb, carry) = ((*limb << 1) | carry, *limb >> (u64::BITS - 1));
+    }
+}
+
+fn shr(a: &mut [u64], shift: u32) {
+    for _ in 0..shift {
+        let mut carry = 0;
+        for limb in a.iter_mut().rev() {
+            (*limb, carry) = ((*limb >> 1) | (carry << (u64::BITS - 1)), *limb & 1);
+        }
+    }
+}
+
+fn trailing_zeros(a: &[u64]) -> u32 {
+    let mut zeros = 0;
+    for &limb in a {
+        if limb != 0 {
+            return zeros + limb.trailing_zeros();
+        }
+        zeros += u64::BITS;
+    }
+    zeros
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::test_rng;
+
+    fn from_u128(n: u128) -> BigUint {
+        BigUint(vec![
+            u64::try_from(n & u128::from(u64::MAX)).unwrap(),
+            u64::try_from(n >> u64::BITS).unwrap(),
+        ])
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = test_rng();
+        for bits in [1, 2, 63, 64, 65, 128, 1000] {
+            let n = BigUint::random(&mut rng, bits, true);
+            assert_eq!(n.bits(), bits);
+            assert_eq!(n.0[0] & 1, 1);
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(from_u128(0).to_string(), "0");
+        assert_eq!(from_u128(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(
+            from_u128(10_u128.pow(19)).to_string(),
+            "10000000000000000000"
+        );
+        assert_eq!(from_u128(u128::MAX).to_hex(), format!("{:x}", u128::MAX));
+        assert_eq!(
+            from_u128(0x1_0000_0000_0000_0001).to_hex(),
+            "10000000000000001"
+        );
+    }
+
+    #[cfg(feature = "base64")]
+    #[test]
+    fn to_be_bytes() {
+        assert_eq!(from_u128(0x1234).to_be_bytes(), [0x12, 0x34]);
+        assert_eq!(from_u128(0).to_be_bytes(), [0]);
+    }
+
+    #[test]
+    fn double_plus_one() {
+        for n in [1, 3, u64::MAX.into(), u128::MAX >> 1] {
+            assert_eq!(
+                from_u128(n).double_plus_one().to_string(),
+                (2 * n + 1).to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn is_probable_prime() {
+        let mut rng = test_rng();
+        for (n, expected) in [
+            (0, false),
+            (1, false),
+            (2, true),
+            (3, true),
+            (4093, true),
+            (4095, false),
+            (16_769_023, true),
+            (561, false),
+            // The largest prime which fits in `u64`.
+            (18_446_744_073_709_551_557, true),
+            // The product of two primes.
+            (4_294_967_291 * 4_294_967_279, false),
+            // The largest prime which fits in `u128`.
+            (u128::MAX - 158, true),
+            // A strong pseudoprime to the bases 2 through 37.
+            (3_317_044_064_679_887_385_961_981, false),
+        ] {
+            assert_eq!(
+                from_u128(n).is_probable_prime(&mut rng, 20),
+                expected,
+                "{n}"
+            );
+        }
+    }
+
+    #[test]
+    fn random_prime() {
+        let mut rng = test_rng();
+        for bits in [2, 3, 16, 64, 65, 256] {
+            let p = super::random_prime(&mut rng, bits, 20, false);
+            assert_eq!(p.bits(), bits);
+        }
+        for bits in [3, 16, 64, 128] {
+            let p = super::random_prime(&mut rng, bits, 20, true);
+            assert_eq!(p.bits(), bits);
+            let mut q = p.0.clone();
+            shr(&mut q, 1);
+            assert!(BigUint(q).is_probable_prime(&mut rng, 20));
+        }
+    }
+}
@@ -0,0 +1,95 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::fmt;
+
+use anyhow::{Context, ensure};
+
+use crate::rng::Rng;
+
+/// The ranks of playing cards, where `T` is 10.
+const RANKS: &[u8; 13] = b"A23456789TJQK";
+
+/// The suits of playing cards, which are clubs, diamonds, hearts and spades.
+const SUITS: &[u8; 4] = b"cdhs";
+
+/// The number of cards in a deck.
+pub const DECK_SIZE: usize = RANKS.len() * SUITS.len();
+
+/// A playing card, which is written as its rank followed by its suit (such as
+/// `Ah` and `Td`).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Card(u8);
+
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (rank, suit) = (
+            usize::from(self.0) % RANKS.len(),
+            usize::from(self.0) / RANKS.len(),
+        );
+        write!(f, "{}{}", char::from(RANKS[rank]), char::from(SUITS[suit]))
+    }
+}
+
+/// Deals `cards` cards to each of `hands` hands from a shuffled deck.
+///
+/// # Errors
+///
+/// Returns [`Err`] if there are more hands than cards in the deck, or if the
+/// deck does not have enough cards.
+pub fn deal(rng: &mut Rng, hands: usize, cards: usize) -> anyhow::Result<Vec<Vec<Card>>> {
+    ensure!(
+        hands <= DECK_SIZE,
+        "cannot deal to more than {DECK_SIZE} hands"
+    );
+    let total = hands.checked_mul(cards).context("too many cards to deal")?;
+    ensure!(
+        total <= DECK_SIZE,
+        "cannot deal {total} cards from a deck of {DECK_SIZE} cards"
+    );
+    let mut deck = (0..).take(DECK_SIZE).map(Card).collect::<Vec<_>>();
+    // Since only the first cards are dealt, a partial Fisher-Yates shuffle is
+    // enough.
+    for i in 0..total {
+        let bound = u64::try_from(DECK_SIZE - i).expect("deck size should fit in `u64`");
+        let j = i + usize::try_from(rng.below(bound)).expect("index should fit in `usize`");
+        deck.swap(i, j);
+    }
+    Ok((0..hands)
+        .map(|i| deck[i * cards..(i + 1) * cards].to_vec())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::rng::test_rng;
+
+    #[test]
+    fn display() {
+        assert_eq!(Card(0).to_string(), "Ac");
+        assert_eq!(Card(22).to_string(), "Td");
+        assert_eq!(Card(51).to_string(), "Ks");
+    }
+
+    #[test]
+    fn deal() {
+        let hands = super::deal(&mut test_rng(), 4, 13).unwrap();
+        assert_eq!(hands.len(), 4);
+        assert!(hands.iter().all(|hand| hand.len() == 13));
+        let cards = hands
+            .iter()
+            .flatten()
+            .map(ToString::to_string)
+            .collect::<HashSet<_>>();
+        assert_eq!(cards.len(), DECK_SIZE);
+        assert!(super::deal(&mut test_rng(), 4, 14).is_err());
+        assert_eq!(super::deal(&mut test_rng(), 2, 0).unwrap(), [[], []]);
+        assert!(super::deal(&mut test_rng(), usize::MAX, 0).is_err());
+        assert!(super::deal(&mut test_rng(), 53, 0).is_err());
+        assert!(super::deal(&mut test_rng(), 2, usize::MAX).is_err());
+    }
+}
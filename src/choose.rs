@@ -0,0 +1,181 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use anyhow::{bail, ensure};
+
+use crate::rng::Rng;
+
+/// An alias table which samples an index with probability proportional to its
+/// weight in constant time.
+///
+/// This is built by Vose's alias method.
+#[derive(Debug)]
+pub struct AliasTable {
+    prob: Vec<f64>,
+    alias: Vec<usize>,
+}
+
+impl AliasTable {
+    /// Creates a new `AliasTable` from `weights`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if any of the weights is negative or not finite, or if
+    /// the sum of the weights is not positive.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn new(weights: &[f64]) -> anyhow::Result<Self> {
+        ensure!(
+            weights.iter().all(|w| w.is_finite() && *w >= 0.0),
+            "weights should be non-negative finite numbers"
+        );
+        let sum = weights.iter().sum::<f64>();
+        ensure!(
+            sum > 0.0 && sum.is_finite(),
+            "sum of weights should be positive"
+        );
+
+        let len = weights.len();
+        let mut prob = weights
+            .iter()
+            .map(|w| w * len as f64 / sum)
+            .collect::<Vec<_>>();
+        let mut alias = (0..len).collect::<Vec<_>>();
+        let (mut small, mut large): (Vec<_>, Vec<_>) = (0..len).partition(|&i| prob[i] < 1.0);
+        while let (Some(&s), Some(&l)) = (small.last(), large.last()) {
+            small.pop();
+            alias[s] = l;
+            prob[l] = (prob[l] + prob[s]) - 1.0;
+            if prob[l] < 1.0 {
+                large.pop();
+                small.push(l);
+            }
+        }
+        // The remaining probabilities are 1 up to rounding errors.
+        for i in large.into_iter().chain(small) {
+            prob[i] = 1.0;
+        }
+        Ok(Self { prob, alias })
+    }
+
+    /// Returns a random index.
+    pub fn sample(&self, rng: &mut Rng) -> usize {
+        let len = u64::try_from(self.prob.len()).expect("length should fit in `u64`");
+        let i = usize::try_from(rng.below(len)).expect("index should fit in `usize`");
+        if rng.next_f64() < self.prob[i] {
+            i
+        } else {
+            self.alias[i]
+        }
+    }
+}
+
+/// Chooses `count` indices without replacement with probability proportional
+/// to `weights`.
+///
+/// An index which has already been chosen is rejected, and the alias table is
+/// rebuilt without the chosen indices when it happens.
+pub fn choose_without_replacement(
+    rng: &mut Rng,
+    weights: &[f64],
+    count: usize,
+) -> anyhow::Result<Vec<usize>> {
+    let available = weights.iter().filter(|&&w| w > 0.0).count();
+    if count > available {
+        bail!("cannot choose {count} items without replacement from {available} items");
+    }
+    let mut table = AliasTable::new(weights)?;
+    let mut chosen = vec![false; weights.len()];
+    let mut indices = Vec::with_capacity(count);
+    while indices.len() < count {
+        let mut index = table.sample(rng);
+        if chosen[index] {
+            let remaining = weights
+                .iter()
+                .zip(&chosen)
+                .map(|(&w, &chosen)| if chosen { 0.0 } else { w })
+                .collect::<Vec<_>>();
+            table = AliasTable::new(&remaining)?;
+            index = table.sample(rng);
+        }
+        chosen[index] = true;
+        indices.push(index);
+    }
+    Ok(indices)
+}
+
+/// Parses an item of the form `ITEM[:WEIGHT]`.
+///
+/// The text after the last `:` is the weight only if it is a number, so an
+/// item such as `http://example.com` can be given without a weight. If the
+/// weight is omitted, it is 1.
+pub fn parse_item(s: &str) -> anyhow::Result<(String, f64)> {
+    let Some((item, weight)) = s
+        .rsplit_once(':')
+        .and_then(|(item, weight)| Some((item, weight.parse::<f64>().ok()?)))
+    else {
+        return Ok((s.into(), 1.0));
+    };
+    ensure!(
+        weight.is_finite() && weight >= 0.0,
+        "weight of `{item}` should be a non-negative finite number"
+    );
+    Ok((item.into(), weight))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::test_rng;
+
+    #[test]
+    fn alias_table() {
+        let table = AliasTable::new(&[70.0, 25.0, 5.0, 0.0]).unwrap();
+        let mut counts = [0; 4];
+        let mut rng = test_rng();
+        for _ in 0..100_000 {
+            counts[table.sample(&mut rng)] += 1;
+        }
+        assert!((69000..71000).contains(&counts[0]), "{counts:?}");
+        assert!((24000..26000).contains(&counts[1]), "{counts:?}");
+        assert!((4500..5500).contains(&counts[2]), "{counts:?}");
+        assert_eq!(counts[3], 0);
+    }
+
+    #[test]
+    fn invalid_weights() {
+        assert!(AliasTable::new(&[]).is_err());
+        assert!(AliasTable::new(&[0.0, 0.0]).is_err());
+        assert!(AliasTable::new(&[1.0, -1.0]).is_err());
+        assert!(AliasTable::new(&[1.0, f64::NAN]).is_err());
+        assert!(AliasTable::new(&[f64::MAX, f64::MAX]).is_err());
+    }
+
+    #[test]
+    fn without_replacement() {
+        let mut rng = test_rng();
+        let mut indices =
+            choose_without_replacement(&mut rng, &[1.0, 1000.0, 1.0, 0.0], 3).unwrap();
+        assert_eq!(indices[0], 1);
+        indices.sort_unstable();
+        assert_eq!(indices, [0, 1, 2]);
+        assert!(choose_without_replacement(&mut rng, &[1.0, 1.0, 0.0], 3).is_err());
+    }
+
+    #[test]
+    fn parse_item() {
+        assert_eq!(super::parse_item("read:70").unwrap(), ("read".into(), 70.0));
+        assert_eq!(super::parse_item("a:b:0.5").unwrap(), ("a:b".into(), 0.5));
+        assert_eq!(super::parse_item("read").unwrap(), ("read".into(), 1.0));
+        assert_eq!(
+            super::parse_item("http://a").unwrap(),
+            ("http://a".into(), 1.0)
+        );
+        assert_eq!(
+            super::parse_item("http://a:2").unwrap(),
+            ("http://a".into(), 2.0)
+        );
+        assert_eq!(super::parse_item("read:x").unwrap(), ("read:x".into(), 1.0));
+        assert!(super::parse_item("read:-1").is_err());
+    }
+}
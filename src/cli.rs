@@ -2,19 +2,95 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use std::io::{self, Write};
+use std::{
+    io::{self, Write},
+    num::NonZeroUsize,
+    path::PathBuf,
+};
 
 use byte_unit::Byte;
-use clap::{CommandFactory, Parser, ValueEnum};
+use clap::{Args, CommandFactory, Parser, Subcommand, ValueEnum};
 use clap_complete::Generator;
 
+use crate::{
+    dice::Expression,
+    dist::Dist,
+    id,
+    int::IntRange,
+    literal,
+    net::{self, Cidr},
+    password,
+    regex::{self, Regex},
+    template::Template,
+    uuid::Payload,
+};
+
 #[derive(Debug, Parser)]
-#[command(version, about, max_term_width(100))]
+#[allow(clippy::struct_excessive_bools)]
+#[command(version, about, max_term_width(100), subcommand_negates_reqs(true))]
 pub struct Opt {
     /// Output in the specified output format.
     #[arg(short, long, value_enum, default_value_t, ignore_case(true))]
     pub format: Format,
 
+    /// Identifier of the generated source code literal.
+    ///
+    /// If this option is not specified, a name which follows the naming
+    /// convention of the language is used.
+    #[arg(long, value_name("NAME"), value_parser(literal::parse_identifier))]
+    pub name: Option<String>,
+
+    /// Number of bytes per line of the generated source code literal.
+    #[arg(long, default_value("12"), value_name("NUMBER"))]
+    pub items_per_line: NonZeroUsize,
+
+    /// Language of the wordlist of the BIP-39 mnemonic.
+    #[cfg(feature = "bip39")]
+    #[arg(
+        long,
+        value_enum,
+        default_value_t,
+        value_name("LANGUAGE"),
+        ignore_case(true)
+    )]
+    pub bip39_language: Bip39Language,
+
+    /// Human-readable part of the Bech32 or Bech32m string.
+    ///
+    /// This option can only be used with the bech32 and bech32m formats.
+    #[cfg(feature = "bech32")]
+    #[arg(
+        long,
+        value_name("HRP"),
+        value_parser(bech32::Hrp::parse),
+        required_if_eq_any([("format", "bech32"), ("format", "bech32m")])
+    )]
+    pub hrp: Option<bech32::Hrp>,
+
+    /// Prefix the output with the multibase code of the output format.
+    #[arg(long)]
+    pub multibase: bool,
+
+    /// Order of the bits in each byte for the binary and octal formats.
+    #[arg(
+        long,
+        value_enum,
+        default_value_t,
+        value_name("ORDER"),
+        ignore_case(true)
+    )]
+    pub bit_order: BitOrder,
+
+    /// Separate the bits of the binary format into groups.
+    #[arg(
+        long,
+        value_enum,
+        default_value_t,
+        value_name("GROUP"),
+        ignore_case(true)
+    )]
+    pub bit_group: BitGroup,
+
     /// Random number generator to use.
     #[arg(
         short,
@@ -22,7 +98,8 @@ pub struct Opt {
         value_enum,
         default_value_t,
         value_name("RNG"),
-        ignore_case(true)
+        ignore_case(true),
+        global(true)
     )]
     pub random_number_generator: Rng,
 
@@ -30,18 +107,185 @@ pub struct Opt {
     ///
     /// If this option is not specified, the RNG seeded via random data from
     /// system sources such as the `getrandom` system call on Linux.
-    #[arg(short, long, value_name("NUMBER"))]
+    #[arg(short, long, value_name("NUMBER"), global(true))]
     pub seed: Option<u64>,
 
+    /// Use the specified Unix timestamp in milliseconds instead of the system
+    /// clock for timestamps.
+    ///
+    /// If this option is not specified and --seed is specified, the clock
+    /// starts at 2025-01-01T00:00:00Z.
+    #[arg(long, value_name("MILLIS"), global(true))]
+    pub clock: Option<u64>,
+
+    /// Advance the clock by <MILLIS> for each timestamp.
+    ///
+    /// This applies when --clock or --seed is specified, and the system clock
+    /// is not affected.
+    #[arg(long, default_value("0"), value_name("MILLIS"), global(true))]
+    pub clock_step: u64,
+
     /// Print information showing the progress of the output of the generated
     /// result. This progress bar does not indicate the progress of the
     /// generation of random bytes itself.
     ///
     /// This option is ignored when the generated bytes are output to a
     /// terminal/tty.
-    #[arg(short, long)]
+    #[arg(short, long, global(true))]
     pub progress: bool,
 
+    /// Generate uniformly distributed integers in the specified range instead
+    /// of bytes.
+    ///
+    /// <MIN..MAX> is an inclusive range, and `MIN..=MAX` is also accepted. Both
+    /// ends can be any integer which fits in a 128-bit signed or unsigned
+    /// integer.
+    #[arg(
+        long,
+        value_name("MIN..MAX"),
+        allow_hyphen_values(true),
+        conflicts_with("length")
+    )]
+    pub int: Option<IntRange>,
+
+    /// Generate random floating-point numbers which follow the specified
+    /// probability distribution instead of bytes.
+    ///
+    /// <DIST> is a name of the distribution optionally followed by `:` and
+    /// comma-separated parameters, such as `normal:0,1`.
+    #[arg(long, value_name("DIST"), conflicts_with_all(["length", "int"]))]
+    pub dist: Option<Dist>,
+
+    /// Number of digits after the decimal point of the generated
+    /// floating-point numbers.
+    ///
+    /// If this option is not specified, the shortest representation which
+    /// round-trips is used.
+    #[arg(
+        long,
+        value_name("DIGITS"),
+        requires("dist"),
+        conflicts_with_all(["length", "int", "packed"])
+    )]
+    pub precision: Option<usize>,
+
+    /// Output the generated floating-point numbers as packed binary of the
+    /// specified type.
+    #[arg(
+        long,
+        value_enum,
+        value_name("TYPE"),
+        requires("dist"),
+        conflicts_with_all(["length", "int"]),
+        ignore_case(true)
+    )]
+    pub packed: Option<FloatType>,
+
+    /// Byte order of the packed floating-point numbers.
+    #[arg(
+        long,
+        value_enum,
+        default_value_t,
+        value_name("ENDIAN"),
+        requires("packed"),
+        conflicts_with_all(["length", "int"]),
+        ignore_case(true)
+    )]
+    pub endian: Endian,
+
+    /// Number of items to generate.
+    ///
+    /// When generating bytes, each of <NUMBER> records of [BYTES] bytes is
+    /// encoded separately and followed by the delimiter. If this option is not
+    /// specified, 1 item is generated, and no delimiter is written after bytes.
+    #[arg(short, long, value_name("NUMBER"), global(true))]
+    pub count: Option<u64>,
+
+    /// String to write after each item.
+    ///
+    /// If this option is not specified, a newline is used.
+    #[arg(
+        short,
+        long,
+        default_value("\n"),
+        hide_default_value(true),
+        value_name("STRING"),
+        global(true)
+    )]
+    pub delimiter: String,
+
+    /// Make all generated items unique.
+    ///
+    /// This is available when generating bytes, integers or network
+    /// identifiers, and cannot be used with the other subcommands. The
+    /// estimated probability that the items contain a duplicate without this
+    /// option is printed to standard error.
+    #[arg(long, conflicts_with("dist"))]
+    pub unique: bool,
+
+    /// Use NUL as the delimiter.
+    #[arg(short('0'), long, conflicts_with("delimiter"), global(true))]
+    pub null: bool,
+
+    /// Generate the least number of bytes which give at least <BITS> of
+    /// entropy instead of [BYTES].
+    #[arg(
+        long,
+        value_name("BITS"),
+        conflicts_with_all(["length", "int", "dist", "output_chars"])
+    )]
+    pub bits: Option<usize>,
+
+    /// Generate the number of bytes which are encoded in exactly <CHARS>
+    /// characters in the output format instead of [BYTES].
+    #[arg(
+        long,
+        value_name("CHARS"),
+        conflicts_with_all(["length", "int", "dist"])
+    )]
+    pub output_chars: Option<usize>,
+
+    /// Generate strings which match the specified regular expression instead
+    /// of bytes.
+    ///
+    /// <PATTERN> supports literals, `.`, character classes (including `\d`,
+    /// `\w`, `\s` and Unicode ranges), groups, alternation and repetition.
+    /// `.` and negated character classes match printable ASCII characters.
+    #[arg(
+        long,
+        value_name("PATTERN"),
+        conflicts_with_all(["length", "int", "dist", "bits", "output_chars", "unique"])
+    )]
+    pub regex: Option<Regex>,
+
+    /// Maximum number of repetitions of `*`, `+` and `{N,}` in the regular
+    /// expression.
+    #[arg(
+        long,
+        default_value_t = regex::MAX_REPEAT,
+        value_name("NUMBER"),
+        requires("regex")
+    )]
+    pub max_repeat: u32,
+
+    /// Render the specified template instead of generating bytes.
+    ///
+    /// Each placeholder in <STRING> is replaced with a random value. The
+    /// placeholders are `{hex:N}`, `{base64:N}`, `{base64url:N}`, `{base32:N}`
+    /// and `{base62:N}` which generate N characters, `{int:MIN..MAX}` and
+    /// `{uuid}`. Use `{{` and `}}` for literal braces.
+    #[arg(
+        long,
+        value_name("STRING"),
+        conflicts_with_all(["length", "int", "dist", "bits", "output_chars", "regex", "unique"])
+    )]
+    pub template: Option<Template>,
+
+    /// Print the computed values, such as the number of random bytes, to
+    /// standard error.
+    #[arg(short, long, global(true))]
+    pub verbose: bool,
+
     /// Generate shell completion.
     ///
     /// The completion is output to standard output.
@@ -53,11 +297,35 @@ pub struct Opt {
     /// [BYTES] can be suffixed with the symbol (B) and the byte prefix (such as
     /// Ki and M). If only a numeric value is specified for [BYTES], it is the
     /// same as specifying the symbol without the byte prefix.
-    #[arg(value_name("BYTES"), required_unless_present("generate_completion"))]
+    #[arg(
+        value_name("BYTES"),
+        required_unless_present_any([
+            "generate_completion",
+            "int",
+            "dist",
+            "bits",
+            "output_chars",
+            "regex",
+            "template"
+        ])
+    )]
     pub length: Option<Byte>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
 }
 
 impl Opt {
+    /// Returns the number of items to generate.
+    pub fn count(&self) -> u64 {
+        self.count.unwrap_or(1)
+    }
+
+    /// Returns the string to write after each item.
+    pub fn delimiter(&self) -> &str {
+        if self.null { "\0" } else { &self.delimiter }
+    }
+
     /// Generates shell completion and print it.
     pub fn print_completion(generator: impl Generator) {
         clap_complete::generate(
@@ -69,6 +337,235 @@ impl Opt {
     }
 }
 
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Generate passwords which follow a character set policy.
+    ///
+    /// Each password is uniformly distributed over all strings which satisfy
+    /// the policy, and its entropy in bits is printed to standard error. If
+    /// none of the character classes are specified, at least one lowercase
+    /// letter, one uppercase letter and one digit are used.
+    Password(PasswordOpt),
+
+    /// Generate passphrases which consist of words picked from a wordlist.
+    ///
+    /// The entropy of a passphrase in bits is printed to standard error.
+    Passphrase(PassphraseOpt),
+
+    /// Generate UUIDs defined in RFC 9562.
+    Uuid(UuidOpt),
+
+    /// Generate ULIDs.
+    Ulid(UlidOpt),
+
+    /// Generate KSUIDs.
+    Ksuid,
+
+    /// Generate Nano IDs.
+    Nanoid(NanoidOpt),
+
+    /// Generate a synthetic dataset which follows a schema.
+    ///
+    /// The number of rows is specified by --count.
+    Dataset(DatasetOpt),
+
+    /// Shuffle lines read from a file or standard input.
+    ///
+    /// With --null, the input is NUL-delimited records instead of lines.
+    Shuffle(ShuffleOpt),
+
+    /// Sample lines read from a file or standard input using reservoir
+    /// sampling.
+    ///
+    /// The sampled records are output in the order in which they appear in the
+    /// input. With --null, the input is NUL-delimited records instead of lines.
+    Sample(SampleOpt),
+
+    /// Choose items at random with probability proportional to their weights.
+    ///
+    /// The number of choices is specified by --count.
+    Choose(ChooseOpt),
+
+    /// Generate a random permutation of the integers from 0 to <N>
+    /// (exclusive).
+    Permute(PermuteOpt),
+
+    /// Roll dice written in dice notation.
+    ///
+    /// Each expression is rolled --count times, and the individual rolls are
+    /// printed with the total.
+    Roll(RollOpt),
+
+    /// Deal playing cards from a shuffled deck of 52 cards.
+    ///
+    /// Each card is written as its rank (A, 2-9, T, J, Q and K) followed by its
+    /// suit (c, d, h and s). The deal is repeated --count times.
+    Deal(DealOpt),
+
+    /// Flip coins.
+    ///
+    /// The number of flips is specified by --count.
+    Flip,
+
+    /// Generate random integers of the specified bit length.
+    ///
+    /// The most significant bit is always set, so each integer has exactly
+    /// <BITS> bits. A warning is printed if the RNG is not cryptographically
+    /// secure.
+    Bigint(BigintOpt),
+
+    /// Generate probable primes of the specified bit length.
+    ///
+    /// Each prime has exactly <BITS> bits and passes the Miller-Rabin test. A
+    /// warning is printed if the RNG is not cryptographically secure.
+    Prime(PrimeOpt),
+
+    /// Generate random network identifiers.
+    ///
+    /// The number of identifiers is specified by --count, and --unique makes
+    /// them unique.
+    Net(NetOpt),
+
+    /// Generate symmetric keys in a key format.
+    ///
+    /// By default, the RNG must be cryptographically secure.
+    #[cfg(feature = "base64")]
+    Key(KeyOpt),
+}
+
+#[derive(Args, Debug)]
+pub struct PasswordOpt {
+    /// Number of characters of each password.
+    #[arg(short, long, default_value("16"), value_name("NUMBER"))]
+    pub length: usize,
+
+    /// Use lowercase letters, and require at least <MIN> of them.
+    #[arg(
+        long,
+        value_name("MIN"),
+        num_args(0..=1),
+        require_equals(true),
+        default_missing_value("0")
+    )]
+    pub lower: Option<usize>,
+
+    /// Use uppercase letters, and require at least <MIN> of them.
+    #[arg(
+        long,
+        value_name("MIN"),
+        num_args(0..=1),
+        require_equals(true),
+        default_missing_value("0")
+    )]
+    pub upper: Option<usize>,
+
+    /// Use digits, and require at least <MIN> of them.
+    #[arg(
+        long,
+        value_name("MIN"),
+        num_args(0..=1),
+        require_equals(true),
+        default_missing_value("0")
+    )]
+    pub digit: Option<usize>,
+
+    /// Use ASCII punctuation characters, and require at least <MIN> of them.
+    #[arg(
+        long,
+        value_name("MIN"),
+        num_args(0..=1),
+        require_equals(true),
+        default_missing_value("0")
+    )]
+    pub symbol: Option<usize>,
+
+    /// Use the specified characters.
+    ///
+    /// The characters must not overlap with the other character classes.
+    #[arg(long, value_name("CHARS"), value_parser(password::parse_custom))]
+    pub custom: Option<String>,
+
+    /// Minimum number of the characters specified by --custom.
+    #[arg(long, default_value("0"), value_name("MIN"), requires("custom"))]
+    pub custom_min: usize,
+
+    /// Exclude ambiguous characters (`0`, `O`, `1`, `I`, `l` and `|`).
+    #[arg(long)]
+    pub exclude_ambiguous: bool,
+
+    /// Allow the RNG which is not cryptographically secure.
+    #[arg(long)]
+    pub allow_insecure: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct PassphraseOpt {
+    /// Number of words of each passphrase.
+    #[arg(
+        short,
+        long,
+        default_value("6"),
+        value_name("NUMBER"),
+        conflicts_with("entropy")
+    )]
+    pub words: NonZeroUsize,
+
+    /// Use the minimum number of words which give at least <BITS> of entropy.
+    #[arg(long, value_name("BITS"))]
+    pub entropy: Option<u32>,
+
+    /// Built-in wordlist to use.
+    #[arg(
+        long,
+        value_enum,
+        default_value_t,
+        value_name("NAME"),
+        ignore_case(true)
+    )]
+    pub list: Wordlist,
+
+    /// Read the wordlist from the specified file.
+    ///
+    /// Each non-empty line is a word. If a line consists of multiple fields
+    /// separated by whitespace, such as a line of a diceware wordlist, the
+    /// last field is used.
+    #[arg(long, value_name("FILE"), conflicts_with("list"))]
+    pub wordlist: Option<PathBuf>,
+
+    /// String to insert between words.
+    ///
+    /// If this option is not specified, a space is used.
+    #[arg(
+        long,
+        default_value(" "),
+        hide_default_value(true),
+        value_name("STRING")
+    )]
+    pub separator: String,
+
+    /// Letter case of words.
+    #[arg(
+        long,
+        value_enum,
+        default_value_t,
+        value_name("CASE"),
+        ignore_case(true)
+    )]
+    pub case: Case,
+
+    /// Append a random digit to a random word.
+    #[arg(long)]
+    pub digit: bool,
+
+    /// Append a random ASCII punctuation character to a random word.
+    #[arg(long)]
+    pub symbol: bool,
+
+    /// Allow the RNG which is not cryptographically secure.
+    #[arg(long)]
+    pub allow_insecure: bool,
+}
+
 #[derive(Clone, Debug, ValueEnum)]
 #[allow(clippy::doc_markdown)]
 #[value(rename_all = "lower")]
@@ -135,6 +632,689 @@ pub enum Format {
     /// Encode the generated bytes as hex string.
     #[cfg(feature = "hex")]
     Hex,
+
+    /// Encode the generated bytes as a string of `0` and `1`.
+    Binary,
+
+    /// Encode the generated bytes as octal digits.
+    Octal,
+
+    /// Encode the generated bytes as proquints.
+    Proquint,
+
+    /// Encode the generated bytes as words of the PGP word list.
+    #[value(name = "pgp-words")]
+    PgpWords,
+
+    /// Encode the generated bytes as a C `uint8_t` array.
+    C,
+
+    /// Encode the generated bytes as a Go `[]byte` slice.
+    Go,
+
+    /// Encode the generated bytes as a JavaScript `Uint8Array`.
+    #[value(alias("js"))]
+    JavaScript,
+
+    /// Encode the generated bytes as a Python `bytes` literal.
+    Python,
+
+    /// Encode the generated bytes as a Rust `[u8; N]` array.
+    Rust,
+
+    /// Encode the generated bytes as a Bech32 string.
+    #[cfg(feature = "bech32")]
+    Bech32,
+
+    /// Encode the generated bytes as a Bech32m string.
+    #[cfg(feature = "bech32")]
+    Bech32m,
+
+    /// Encode the generated bytes as a BIP-39 mnemonic.
+    #[cfg(feature = "bip39")]
+    Bip39,
+}
+
+#[cfg(feature = "bip39")]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum Bip39Language {
+    /// English.
+    #[default]
+    English,
+
+    /// Simplified Chinese.
+    #[cfg(feature = "bip39-all-languages")]
+    ChineseSimplified,
+
+    /// Traditional Chinese.
+    #[cfg(feature = "bip39-all-languages")]
+    ChineseTraditional,
+
+    /// Czech.
+    #[cfg(feature = "bip39-all-languages")]
+    Czech,
+
+    /// French.
+    #[cfg(feature = "bip39-all-languages")]
+    French,
+
+    /// Italian.
+    #[cfg(feature = "bip39-all-languages")]
+    Italian,
+
+    /// Japanese.
+    #[cfg(feature = "bip39-all-languages")]
+    Japanese,
+
+    /// Korean.
+    #[cfg(feature = "bip39-all-languages")]
+    Korean,
+
+    /// Portuguese.
+    #[cfg(feature = "bip39-all-languages")]
+    Portuguese,
+
+    /// Spanish.
+    #[cfg(feature = "bip39-all-languages")]
+    Spanish,
+}
+
+#[cfg(feature = "bip39")]
+impl From<Bip39Language> for bip39::Language {
+    fn from(language: Bip39Language) -> Self {
+        match language {
+            Bip39Language::English => Self::English,
+            #[cfg(feature = "bip39-all-languages")]
+            Bip39Language::ChineseSimplified => Self::SimplifiedChinese,
+            #[cfg(feature = "bip39-all-languages")]
+            Bip39Language::ChineseTraditional => Self::TraditionalChinese,
+            #[cfg(feature = "bip39-all-languages")]
+            Bip39Language::Czech => Self::Czech,
+            #[cfg(feature = "bip39-all-languages")]
+            Bip39Language::French => Self::French,
+            #[cfg(feature = "bip39-all-languages")]
+            Bip39Language::Italian => Self::Italian,
+            #[cfg(feature = "bip39-all-languages")]
+            Bip39Language::Japanese => Self::Japanese,
+            #[cfg(feature = "bip39-all-languages")]
+            Bip39Language::Korean => Self::Korean,
+            #[cfg(feature = "bip39-all-languages")]
+            Bip39Language::Portuguese => Self::Portuguese,
+            #[cfg(feature = "bip39-all-languages")]
+            Bip39Language::Spanish => Self::Spanish,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+#[value(rename_all = "lower")]
+pub enum BitOrder {
+    /// The most significant bit first.
+    #[default]
+    Msb,
+
+    /// The least significant bit first.
+    Lsb,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+#[value(rename_all = "lower")]
+pub enum BitGroup {
+    /// Do not separate the bits.
+    #[default]
+    None,
+
+    /// Separate the bits every 4 bits.
+    Nibble,
+
+    /// Separate the bits every 8 bits.
+    Byte,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+#[value(rename_all = "lower")]
+pub enum FloatType {
+    /// IEEE 754 binary32.
+    F32,
+
+    /// IEEE 754 binary64.
+    F64,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+#[value(rename_all = "lower")]
+pub enum Endian {
+    /// Little-endian.
+    #[default]
+    Little,
+
+    /// Big-endian.
+    Big,
+}
+
+#[derive(Args, Debug)]
+pub struct UuidOpt {
+    /// Version of UUIDs.
+    #[arg(
+        short('t'),
+        long("type"),
+        value_enum,
+        default_value_t,
+        value_name("VERSION"),
+        ignore_case(true)
+    )]
+    pub version: UuidVersion,
+
+    /// Style of UUIDs.
+    #[arg(
+        long,
+        value_enum,
+        default_value_t,
+        value_name("STYLE"),
+        ignore_case(true)
+    )]
+    pub style: UuidStyle,
+
+    /// Hexadecimal digits which the version 8 UUIDs begin with.
+    ///
+    /// The rest of the bits are random, and the version and variant fields
+    /// override the corresponding bits.
+    #[arg(long, value_name("HEX"))]
+    pub payload: Option<Payload>,
+}
+
+#[derive(Args, Debug)]
+pub struct UlidOpt {
+    /// Increment the random component of the previous ULID instead of
+    /// generating a new one within the same millisecond.
+    #[arg(long)]
+    pub monotonic: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct NanoidOpt {
+    /// Characters to use.
+    ///
+    /// If this option is not specified, A-Z, a-z, 0-9, `_` and `-` are used.
+    #[arg(long, value_name("CHARS"), value_parser(id::parse_alphabet))]
+    pub alphabet: Option<String>,
+
+    /// Number of characters of each Nano ID.
+    #[arg(
+        long,
+        default_value("21"),
+        value_name("NUMBER"),
+        conflicts_with("bits")
+    )]
+    pub size: usize,
+
+    /// Use the least number of characters which give at least <BITS> of
+    /// entropy.
+    #[arg(long, value_name("BITS"))]
+    pub bits: Option<u32>,
+}
+
+#[derive(Args, Debug)]
+pub struct DatasetOpt {
+    /// Output format of the dataset.
+    #[arg(
+        short('t'),
+        long("type"),
+        value_enum,
+        default_value_t,
+        value_name("FORMAT"),
+        ignore_case(true)
+    )]
+    pub format: DatasetFormat,
+
+    /// Do not write the header row of CSV.
+    #[arg(long)]
+    pub no_header: bool,
+
+    /// Schema file of the dataset.
+    ///
+    /// The schema is written in TOML, or in JSON if the file has the `.json`
+    /// extension.
+    #[arg(value_name("SCHEMA"))]
+    pub schema: PathBuf,
+}
+
+#[derive(Args, Debug)]
+pub struct ShuffleOpt {
+    /// Output at most <COUNT> records.
+    #[arg(short('n'), long, value_name("COUNT"))]
+    pub head_count: Option<u64>,
+
+    /// Size of the memory buffer.
+    ///
+    /// If the input is larger than this, the records are shuffled using
+    /// temporary files. <SIZE> can be suffixed with the symbol (B) and the byte
+    /// prefix (such as Ki and M).
+    #[arg(short('S'), long, default_value("64 MiB"), value_name("SIZE"))]
+    pub buffer_size: Byte,
+
+    /// Input file.
+    ///
+    /// If [FILE] is not specified, or "-" is specified, the input is read from
+    /// standard input.
+    #[arg(value_name("FILE"))]
+    pub input: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+pub struct SampleOpt {
+    /// Number of records to sample.
+    #[arg(short('n'), long, value_name("COUNT"))]
+    pub size: usize,
+
+    /// Select each record with probability proportional to the weight in the
+    /// specified field (1-based).
+    #[arg(short, long, value_name("FIELD"), value_parser(clap::value_parser!(u16).range(1..)))]
+    pub weight_field: Option<u16>,
+
+    /// String which separates the fields.
+    ///
+    /// If this option is not specified, the fields are separated by
+    /// whitespace.
+    #[arg(short('F'), long, value_name("STRING"), requires("weight_field"))]
+    pub field_separator: Option<String>,
+
+    /// Input file.
+    ///
+    /// If [FILE] is not specified, or "-" is specified, the input is read from
+    /// standard input.
+    #[arg(value_name("FILE"))]
+    pub input: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+pub struct ChooseOpt {
+    /// Do not choose the same item more than once.
+    #[arg(long)]
+    pub without_replacement: bool,
+
+    /// Read the items from the specified file, one per line.
+    #[arg(long, value_name("FILE"), conflicts_with("items"))]
+    pub file: Option<PathBuf>,
+
+    /// Items to choose from.
+    ///
+    /// Each item is of the form ITEM[:WEIGHT]. If the weight is omitted, it is
+    /// 1.
+    #[arg(value_name("ITEM"), required_unless_present("file"))]
+    pub items: Vec<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct PermuteOpt {
+    /// Output only the first <COUNT> elements.
+    #[arg(short('n'), long, value_name("COUNT"))]
+    pub head_count: Option<u128>,
+
+    /// Output the elements in ascending order.
+    ///
+    /// This generates a random combination of <COUNT> elements. <COUNT> must
+    /// be at most 1048576.
+    #[arg(long, requires("head_count"))]
+    pub combination: bool,
+
+    /// Output only the element at the specified position of the permutation.
+    ///
+    /// This option can be specified multiple times.
+    #[arg(
+        short,
+        long,
+        value_name("INDEX"),
+        conflicts_with("head_count"),
+        conflicts_with("combination")
+    )]
+    pub index: Vec<u128>,
+
+    /// Number of elements.
+    #[arg(value_name("N"))]
+    pub len: u128,
+}
+
+#[derive(Args, Debug)]
+pub struct RollOpt {
+    /// Output format.
+    #[arg(
+        short('t'),
+        long("type"),
+        value_enum,
+        default_value_t,
+        value_name("FORMAT")
+    )]
+    pub format: ResultFormat,
+
+    /// Dice expressions such as "4d6kh3" and "1d20+5".
+    #[arg(value_name("EXPRESSION"), required(true))]
+    pub expressions: Vec<Expression>,
+}
+
+#[derive(Args, Debug)]
+pub struct DealOpt {
+    /// Number of hands.
+    #[arg(short('H'), long, default_value("1"), value_name("NUMBER"))]
+    pub hands: usize,
+
+    /// Output format.
+    #[arg(
+        short('t'),
+        long("type"),
+        value_enum,
+        default_value_t,
+        value_name("FORMAT")
+    )]
+    pub format: ResultFormat,
+
+    /// Number of cards of each hand.
+    #[arg(value_name("CARDS"))]
+    pub cards: usize,
+}
+
+#[derive(Args, Debug)]
+pub struct BigintOpt {
+    /// Generate only odd integers.
+    #[arg(long)]
+    pub odd: bool,
+
+    /// Output format.
+    #[arg(
+        short('t'),
+        long("type"),
+        value_enum,
+        default_value_t,
+        value_name("FORMAT")
+    )]
+    pub format: BigIntFormat,
+
+    /// Number of bits of each integer.
+    #[arg(value_name("BITS"), value_parser(clap::value_parser!(u32).range(1..)))]
+    pub bits: u32,
+}
+
+#[derive(Args, Debug)]
+pub struct PrimeOpt {
+    /// Number of rounds of the Miller-Rabin test.
+    ///
+    /// The probability that a composite is reported as prime is at most
+    /// 4^(-<NUMBER>).
+    #[arg(
+        long,
+        default_value("64"),
+        value_name("NUMBER"),
+        value_parser(clap::value_parser!(u32).range(1..))
+    )]
+    pub rounds: u32,
+
+    /// Generate safe primes.
+    ///
+    /// A safe prime p is a prime such that (p - 1) / 2 is also prime. This is
+    /// much slower than generating primes.
+    #[arg(long)]
+    pub safe: bool,
+
+    /// Output format.
+    #[arg(
+        short('t'),
+        long("type"),
+        value_enum,
+        default_value_t,
+        value_name("FORMAT")
+    )]
+    pub format: BigIntFormat,
+
+    /// Number of bits of each prime.
+    #[arg(value_name("BITS"), value_parser(clap::value_parser!(u32).range(2..)))]
+    pub bits: u32,
+}
+
+#[derive(Args, Debug)]
+pub struct NetOpt {
+    #[command(subcommand)]
+    pub command: NetCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum NetCommand {
+    /// Generate locally administered unicast MAC addresses.
+    Mac,
+
+    /// Generate IPv4 or IPv6 addresses in a CIDR block.
+    ///
+    /// The addresses are uniformly distributed over the block.
+    Ip(IpOpt),
+
+    /// Generate port numbers in a range.
+    Port(PortOpt),
+}
+
+#[derive(Args, Debug)]
+pub struct IpOpt {
+    /// Exclude the network address and the broadcast address.
+    ///
+    /// For IPv6, the Subnet-Router anycast address is excluded. Nothing is
+    /// excluded from a block of at most 2 addresses.
+    #[arg(long)]
+    pub hosts_only: bool,
+
+    /// CIDR block, such as `192.168.0.0/24` or `2001:db8::/32`.
+    #[arg(value_name("CIDR"))]
+    pub cidr: Cidr,
+}
+
+#[derive(Args, Debug)]
+pub struct PortOpt {
+    /// Range of the port numbers.
+    ///
+    /// <MIN..MAX> is an inclusive range, and `MIN..=MAX` is also accepted. If
+    /// this is not specified, the dynamic ports are used.
+    #[arg(
+        default_value("49152..65535"),
+        value_name("MIN..MAX"),
+        value_parser(net::parse_port_range)
+    )]
+    pub range: IntRange,
+}
+
+#[cfg(feature = "base64")]
+#[derive(Args, Debug)]
+pub struct KeyOpt {
+    /// Algorithm of the JWK.
+    ///
+    /// If --length is not specified, the length of the key is the one which the
+    /// algorithm requires.
+    #[arg(long, value_enum, value_name("ALG"), ignore_case(true))]
+    pub alg: Option<JwkAlgorithm>,
+
+    /// Key ID of the JWK.
+    #[arg(long, value_name("KID"))]
+    pub kid: Option<String>,
+
+    /// Number of bytes of the JWK.
+    ///
+    /// If neither this option nor --alg is specified, 32 bytes are generated.
+    #[arg(short, long, value_name("BYTES"))]
+    pub length: Option<NonZeroUsize>,
+
+    /// Allow the RNG which is not cryptographically secure.
+    #[arg(long)]
+    pub allow_insecure: bool,
+
+    /// Format of the key.
+    #[arg(value_enum, value_name("TYPE"), ignore_case(true))]
+    pub key_type: KeyType,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+#[value(rename_all = "lower")]
+pub enum UuidVersion {
+    /// Version 4, which consists of random bits.
+    #[default]
+    V4,
+
+    /// Version 7, which consists of a Unix timestamp and random bits.
+    V7,
+
+    /// Version 8, which consists of the payload and random bits.
+    V8,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+#[value(rename_all = "lower")]
+pub enum UuidStyle {
+    /// Hyphenated lowercase hexadecimal digits.
+    #[default]
+    Hyphenated,
+
+    /// Lowercase hexadecimal digits without hyphens.
+    Simple,
+
+    /// URN.
+    Urn,
+
+    /// Hyphenated lowercase hexadecimal digits surrounded by braces.
+    Braced,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+#[value(rename_all = "lower")]
+pub enum DatasetFormat {
+    /// Comma-separated values.
+    #[default]
+    Csv,
+
+    /// Newline-delimited JSON.
+    Ndjson,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum ResultFormat {
+    /// Human-readable text.
+    #[default]
+    Text,
+
+    /// JSON.
+    Json,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum BigIntFormat {
+    /// Decimal digits.
+    #[default]
+    #[value(alias("dec"))]
+    Decimal,
+
+    /// Lowercase hexadecimal digits.
+    Hex,
+
+    /// Base64 of the big-endian bytes.
+    #[cfg(feature = "base64")]
+    Base64,
+}
+
+#[cfg(feature = "base64")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+#[allow(clippy::doc_markdown)]
+#[value(rename_all = "lower")]
+pub enum KeyType {
+    /// JSON Web Key of the symmetric key type (`oct`).
+    Jwk,
+
+    /// WireGuard private key, which is a clamped X25519 private key in base64.
+    #[value(alias("x25519"))]
+    WireGuard,
+
+    /// age secret key in Bech32.
+    #[cfg(feature = "bech32")]
+    Age,
+}
+
+#[cfg(feature = "base64")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+#[value(rename_all = "UPPER")]
+pub enum JwkAlgorithm {
+    /// HMAC using SHA-256.
+    Hs256,
+
+    /// HMAC using SHA-384.
+    Hs384,
+
+    /// HMAC using SHA-512.
+    Hs512,
+
+    /// AES Key Wrap using 128-bit key.
+    A128Kw,
+
+    /// AES Key Wrap using 192-bit key.
+    A192Kw,
+
+    /// AES Key Wrap using 256-bit key.
+    A256Kw,
+
+    /// AES GCM using 128-bit key.
+    A128Gcm,
+
+    /// AES GCM using 192-bit key.
+    A192Gcm,
+
+    /// AES GCM using 256-bit key.
+    A256Gcm,
+
+    /// Key wrapping with AES GCM using 128-bit key.
+    A128GcmKw,
+
+    /// Key wrapping with AES GCM using 192-bit key.
+    A192GcmKw,
+
+    /// Key wrapping with AES GCM using 256-bit key.
+    A256GcmKw,
+}
+
+#[cfg(feature = "base64")]
+impl JwkAlgorithm {
+    /// Returns the number of bytes of the key which the algorithm requires.
+    ///
+    /// For HMAC, this is the minimum.
+    pub const fn key_len(self) -> usize {
+        match self {
+            Self::A128Kw | Self::A128Gcm | Self::A128GcmKw => 16,
+            Self::A192Kw | Self::A192Gcm | Self::A192GcmKw => 24,
+            Self::Hs256 | Self::A256Kw | Self::A256Gcm | Self::A256GcmKw => 32,
+            Self::Hs384 => 48,
+            Self::Hs512 => 64,
+        }
+    }
+
+    /// Returns `true` if the algorithm is HMAC.
+    pub const fn is_hmac(self) -> bool {
+        matches!(self, Self::Hs256 | Self::Hs384 | Self::Hs512)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum Wordlist {
+    /// The EFF large wordlist, which contains 7776 words.
+    #[default]
+    EffLarge,
+
+    /// The EFF short wordlist, which contains 1296 words.
+    EffShort,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+#[value(rename_all = "lower")]
+pub enum Case {
+    /// Lowercase.
+    #[default]
+    Lower,
+
+    /// Uppercase.
+    Upper,
+
+    /// Capitalize the first letter of each word.
+    Title,
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq, ValueEnum)]
@@ -259,6 +1439,27 @@ pub enum Rng {
     Xoshiro512StarStar,
 }
 
+impl Rng {
+    /// Returns `true` if the RNG is a CSPRNG.
+    pub const fn is_cryptographically_secure(&self) -> bool {
+        match self {
+            Self::ChaCha8 | Self::ChaCha12 | Self::ChaCha20 => true,
+            #[cfg(feature = "hc")]
+            Self::Hc128 => true,
+            _ => false,
+        }
+    }
+}
+
+/// Returns the name of `value` on the command line.
+pub fn value_name(value: &impl ValueEnum) -> String {
+    value
+        .to_possible_value()
+        .expect("value should have a name")
+        .get_name()
+        .to_owned()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -283,8 +1484,82 @@ mod tests {
         assert_eq!(Format::default(), Format::Raw);
     }
 
+    #[test]
+    fn default_bit_order() {
+        assert_eq!(BitOrder::default(), BitOrder::Msb);
+    }
+
+    #[test]
+    fn default_bit_group() {
+        assert_eq!(BitGroup::default(), BitGroup::None);
+    }
+
+    #[test]
+    fn default_endian() {
+        assert_eq!(Endian::default(), Endian::Little);
+    }
+
+    #[test]
+    fn default_wordlist() {
+        assert_eq!(Wordlist::default(), Wordlist::EffLarge);
+    }
+
+    #[test]
+    fn default_case() {
+        assert_eq!(Case::default(), Case::Lower);
+    }
+
+    #[test]
+    fn default_uuid_version() {
+        assert_eq!(UuidVersion::default(), UuidVersion::V4);
+    }
+
+    #[test]
+    fn default_uuid_style() {
+        assert_eq!(UuidStyle::default(), UuidStyle::Hyphenated);
+    }
+
+    #[test]
+    fn default_dataset_format() {
+        assert_eq!(DatasetFormat::default(), DatasetFormat::Csv);
+    }
+
+    #[test]
+    fn default_big_int_format() {
+        assert_eq!(BigIntFormat::default(), BigIntFormat::Decimal);
+    }
+
+    #[test]
+    fn default_result_format() {
+        assert_eq!(ResultFormat::default(), ResultFormat::Text);
+    }
+
     #[test]
     fn default_rng() {
         assert_eq!(Rng::default(), Rng::ChaCha12);
     }
+
+    #[test]
+    fn is_cryptographically_secure() {
+        assert!(Rng::ChaCha8.is_cryptographically_secure());
+        assert!(Rng::ChaCha12.is_cryptographically_secure());
+        assert!(Rng::ChaCha20.is_cryptographically_secure());
+        #[cfg(feature = "hc")]
+        assert!(Rng::Hc128.is_cryptographically_secure());
+        #[cfg(feature = "pcg")]
+        assert!(!Rng::Pcg64.is_cryptographically_secure());
+        assert!(!Rng::SplitMix64.is_cryptographically_secure());
+    }
+
+    #[cfg(feature = "base64")]
+    #[test]
+    fn jwk_algorithm_key_len() {
+        assert_eq!(JwkAlgorithm::Hs256.key_len(), 32);
+        assert_eq!(JwkAlgorithm::Hs512.key_len(), 64);
+        assert_eq!(JwkAlgorithm::A128Kw.key_len(), 16);
+        assert_eq!(JwkAlgorithm::A192Gcm.key_len(), 24);
+        assert_eq!(JwkAlgorithm::A256GcmKw.key_len(), 32);
+        assert!(JwkAlgorithm::Hs384.is_hmac());
+        assert!(!JwkAlgorithm::A256Gcm.is_hmac());
+    }
 }
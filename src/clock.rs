@@ -0,0 +1,76 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::Context;
+
+/// The timestamp at which the clock starts when a random seed is specified and
+/// no timestamp is specified (2025-01-01T00:00:00Z).
+pub const SEEDED_START: u64 = 1_735_689_600_000;
+
+/// A source of timestamps in milliseconds since the Unix epoch.
+#[derive(Debug)]
+pub enum Clock {
+    /// The system clock.
+    System,
+
+    /// A clock which starts at the specified timestamp and advances by `step`
+    /// each time it is read.
+    ///
+    /// `next` is [`None`] if the next timestamp overflows.
+    Fixed { next: Option<u64>, step: u64 },
+}
+
+impl Clock {
+    /// Creates a new `Clock`.
+    ///
+    /// If `start` is [`None`], the system clock is used.
+    pub const fn new(start: Option<u64>, step: u64) -> Self {
+        match start {
+            Some(next) => Self::Fixed {
+                next: Some(next),
+                step,
+            },
+            None => Self::System,
+        }
+    }
+
+    /// Returns the current timestamp.
+    pub fn now(&mut self) -> anyhow::Result<u64> {
+        match self {
+            Self::System => {
+                let elapsed = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .context("system time is before the Unix epoch")?;
+                u64::try_from(elapsed.as_millis()).context("system time is too far in the future")
+            }
+            Self::Fixed { next, step } => {
+                let now = next.context("timestamp overflowed")?;
+                *next = now.checked_add(*step);
+                Ok(now)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_clock() {
+        let mut clock = Clock::new(Some(1000), 10);
+        assert_eq!(clock.now().unwrap(), 1000);
+        assert_eq!(clock.now().unwrap(), 1010);
+        assert_eq!(clock.now().unwrap(), 1020);
+        let mut clock = Clock::new(Some(u64::MAX - 1), 1);
+        assert_eq!(clock.now().unwrap(), u64::MAX - 1);
+        assert_eq!(clock.now().unwrap(), u64::MAX);
+        assert!(clock.now().is_err());
+        let mut clock = Clock::new(Some(u64::MAX), 0);
+        assert_eq!(clock.now().unwrap(), u64::MAX);
+        assert_eq!(clock.now().unwrap(), u64::MAX);
+    }
+}
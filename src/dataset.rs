@@ -0,0 +1,498 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::{collections::HashSet, fmt::Write};
+
+use anyhow::{Context, ensure};
+use rand_distr::{Distribution, weighted::WeightedIndex};
+use serde::Deserialize;
+
+use crate::{
+    cli::UuidStyle,
+    dist::Dist,
+    int::{Int, IntRange},
+    regex::{self, Regex},
+    rng::Rng,
+    uuid::Uuid,
+};
+
+/// A schema of a synthetic dataset.
+#[derive(Debug)]
+pub struct Schema(Vec<Column>);
+
+#[derive(Debug)]
+struct Column {
+    name: String,
+    null: f64,
+    generator: Generator,
+}
+
+#[derive(Debug)]
+enum Generator {
+    Int(IntRange),
+    Float {
+        dist: Dist,
+        precision: Option<usize>,
+    },
+    Enum {
+        values: Vec<String>,
+        weights: Option<WeightedIndex<f64>>,
+    },
+    Regex {
+        regex: Regex,
+        max_repeat: u32,
+    },
+    Uuid,
+    Timestamp(IntRange),
+}
+
+/// A generated value of a field.
+#[derive(Debug)]
+enum Value {
+    Null,
+    Int(Int),
+    Float(f64, Option<usize>),
+    String(String),
+}
+
+/// A column of a schema as written in a file.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawColumn {
+    name: String,
+    #[serde(rename = "type")]
+    kind: ColumnType,
+    range: Option<String>,
+    dist: Option<String>,
+    precision: Option<usize>,
+    values: Option<Vec<String>>,
+    weights: Option<Vec<f64>>,
+    pattern: Option<String>,
+    max_repeat: Option<u32>,
+    start: Option<String>,
+    end: Option<String>,
+    #[serde(default)]
+    null: f64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawSchema {
+    columns: Vec<RawColumn>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum ColumnType {
+    Int,
+    Float,
+    Enum,
+    Regex,
+    Uuid,
+    Timestamp,
+}
+
+impl Schema {
+    /// Parses a schema written in TOML.
+    pub fn from_toml(s: &str) -> anyhow::Result<Self> {
+        let raw = toml::from_str::<RawSchema>(s).context("could not parse TOML")?;
+        Self::new(raw)
+    }
+
+    /// Parses a schema written in JSON.
+    pub fn from_json(s: &str) -> anyhow::Result<Self> {
+        let raw = serde_json::from_str::<RawSchema>(s).context("could not parse JSON")?;
+        Self::new(raw)
+    }
+
+    fn new(raw: RawSchema) -> anyhow::Result<Self> {
+        ensure!(
+            !raw.columns.is_empty(),
+            "schema should have at least one column"
+        );
+        let mut names = HashSet::new();
+        let columns = raw
+            .columns
+            .into_iter()
+            .map(|column| {
+                ensure!(
+                    names.insert(column.name.clone()),
+                    "duplicate column `{}`",
+                    column.name
+                );
+                let name = column.name.clone();
+                Column::new(column).with_context(|| format!("invalid column `{name}`"))
+            })
+            .collect::<anyhow::Result<_>>()?;
+        Ok(Self(columns))
+    }
+
+    /// Writes the names of the columns as a CSV header.
+    pub fn write_csv_header(&self, s: &mut String) {
+        for (i, column) in self.0.iter().enumerate() {
+            if i > 0 {
+                s.push(',');
+            }
+            push_csv_field(s, &column.name);
+        }
+        s.push('\n');
+    }
+
+    /// Generates a row and writes it as a CSV record.
+    pub fn write_csv_row(&self, rng: &mut Rng, s: &mut String) {
+        for (i, column) in self.0.iter().enumerate() {
+            if i > 0 {
+                s.push(',');
+            }
+            match column.generate(rng) {
+                Value::Null => {}
+                Value::String(value) => push_csv_field(s, &value),
+                value => value.write_number(s),
+            }
+        }
+        s.push('\n');
+    }
+
+    /// Generates a row and writes it as a JSON object on a line.
+    pub fn write_ndjson_row(&self, rng: &mut Rng, s: &mut String) {
+        s.push('{');
+        for (i, column) in self.0.iter().enumerate() {
+            if i > 0 {
+                s.push(',');
+            }
+            push_json_string(s, &column.name);
+            s.push(':');
+            match column.generate(rng) {
+                Value::Null => s.push_str("null"),
+                Value::String(value) => push_json_string(s, &value),
+                Value::Float(value, _) if !value.is_finite() => s.push_str("null"),
+                value => value.write_number(s),
+            }
+        }
+        s.push_str("}\n");
+    }
+}
+
+impl Column {
+    fn new(raw: RawColumn) -> anyhow::Result<Self> {
+        ensure!(
+            (0.0..=1.0).contains(&raw.null),
+            "null ratio should be between 0 and 1"
+        );
+        let generator = match raw.kind {
+            ColumnType::Int => {
+                let range = raw.range.context("`range` is required")?;
+                Generator::Int(range.parse()?)
+            }
+            ColumnType::Float => Generator::Float {
+                dist: raw.dist.as_deref().unwrap_or("uniform").parse()?,
+                precision: raw.precision,
+            },
+            ColumnType::Enum => {
+                let values = raw.values.context("`values` is required")?;
+                ensure!(!values.is_empty(), "`values` should not be empty");
+                let weights = raw
+                    .weights
+                    .map(|weights| {
+                        ensure!(
+                            weights.len() == values.len(),
+                            "`weights` should have the same length as `values`"
+                        );
+                        WeightedIndex::new(weights).context("invalid weights")
+                    })
+                    .transpose()?;
+                Generator::Enum { values, weights }
+            }
+            ColumnType::Regex => Generator::Regex {
+                regex: raw.pattern.context("`pattern` is required")?.parse()?,
+                max_repeat: raw.max_repeat.unwrap_or(regex::MAX_REPEAT),
+            },
+            ColumnType::Uuid => Generator::Uuid,
+            ColumnType::Timestamp => {
+                let start = parse_timestamp(raw.start.as_deref().context("`start` is required")?)?;
+                let end = parse_timestamp(raw.end.as_deref().context("`end` is required")?)?;
+                ensure!(start <= end, "`start` should not be later than `end`");
+                Generator::Timestamp(IntRange::Signed {
+                    min: start.into(),
+                    max: end.into(),
+                })
+            }
+        };
+        Ok(Self {
+            name: raw.name,
+            null: raw.null,
+            generator,
+        })
+    }
+
+    fn generate(&self, rng: &mut Rng) -> Value {
+        if self.null > 0.0 && rng.next_f64() < self.null {
+            return Value::Null;
+        }
+        match &self.generator {
+            Generator::Int(range) => Value::Int(range.sample(rng)),
+            Generator::Float { dist, precision } => Value::Float(dist.sample(rng), *precision),
+            Generator::Enum { values, weights } => {
+                let index = if let Some(weights) = weights {
+                    weights.sample(rng)
+                } else {
+                    let len = u64::try_from(values.len()).expect("values should be small");
+                    usize::try_from(rng.below(len)).expect("index should fit in `usize`")
+                };
+                Value::String(values[index].clone())
+            }
+            Generator::Regex { regex, max_repeat } => {
+                Value::String(regex.generate(rng, *max_repeat))
+            }
+            Generator::Uuid => Value::String(Uuid::new_v4(rng).encode(UuidStyle::Hyphenated)),
+            Generator::Timestamp(range) => {
+                let Int::Signed(seconds) = range.sample(rng) else {
+                    unreachable!("timestamp range should be signed");
+                };
+                let seconds = i64::try_from(seconds).expect("timestamp should fit in `i64`");
+                Value::String(format_timestamp(seconds))
+            }
+        }
+    }
+}
+
+impl Value {
+    fn write_number(&self, s: &mut String) {
+        let result = match self {
+            Self::Int(value) => write!(s, "{value}"),
+            Self::Float(value, Some(precision)) => write!(s, "{value:.precision$}"),
+            Self::Float(value, None) => write!(s, "{value}"),
+            Self::Null | Self::String(_) => unreachable!("value should be a number"),
+        };
+        result.expect("writing to `String` should not fail");
+    }
+}
+
+/// Appends `field` to `s`, quoting it if necessary.
+fn push_csv_field(s: &mut String, field: &str) {
+    if field.contains([',', '"', '\n', '\r']) {
+        s.push('"');
+        s.push_str(&field.replace('"', "\"\""));
+        s.push('"');
+    } else {
+        s.push_str(field);
+    }
+}
+
+/// Appends `value` as a JSON string to `s`.
+fn push_json_string(s: &mut String, value: &str) {
+    s.push_str(&serde_json::to_string(value).expect("string should be serializable"));
+}
+
+/// Parses an RFC 3339 timestamp in UTC, such as `2025-01-01T00:00:00Z`, or a
+/// date, such as `2025-01-01`, into seconds since the Unix epoch.
+///
+/// The year should be between 0 and 9999, which RFC 3339 can represent.
+fn parse_timestamp(s: &str) -> anyhow::Result<i64> {
+    let parse = || -> Option<i64> {
+        let (date, time) = s
+            .split_once(['T', 't', ' '])
+            .map_or((s, None), |(date, time)| (date, Some(time)));
+        let mut date = date.splitn(3, '-').map(str::parse::<i64>);
+        let (year, month, day) = (date.next()?.ok()?, date.next()?.ok()?, date.next()?.ok()?);
+        if !(0..=9999).contains(&year)
+            || !(1..=12).contains(&month)
+            || !(1..=days_in_month(year, month)).contains(&day)
+        {
+            return None;
+        }
+        let seconds = match time {
+            Some(time) => {
+                let time = time.strip_suffix(['Z', 'z'])?;
+                let mut time = time.splitn(3, ':').map(str::parse::<i64>);
+                let (hour, minute, second) =
+                    (time.next()?.ok()?, time.next()?.ok()?, time.next()?.ok()?);
+                if hour > 23 || minute > 59 || second > 59 || hour.min(minute).min(second) < 0 {
+                    return None;
+                }
+                hour * 3600 + minute * 60 + second
+            }
+            None => 0,
+        };
+        Some(days_from_civil(year, month, day) * 86400 + seconds)
+    };
+    parse().with_context(|| format!("invalid timestamp `{s}`"))
+}
+
+/// Formats seconds since the Unix epoch as an RFC 3339 timestamp in UTC.
+fn format_timestamp(seconds: i64) -> String {
+    let (days, seconds) = (seconds.div_euclid(86400), seconds.rem_euclid(86400));
+    let (year, month, day) = civil_from_days(days);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+const fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// These conversions between dates and days since the Unix epoch are based on
+// the algorithms in <https://howardhinnant.github.io/date_algorithms.html>.
+const fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+const fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+    let year = year_of_era + era * 400;
+    (if month <= 2 { year + 1 } else { year }, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::test_rng;
+
+    const SCHEMA: &str = r#"
+[[columns]]
+name = "id"
+type = "uuid"
+
+[[columns]]
+name = "age"
+type = "int"
+range = "18..99"
+null = 0.5
+
+[[columns]]
+name = "score"
+type = "float"
+dist = "normal:0,1"
+precision = 2
+
+[[columns]]
+name = "plan"
+type = "enum"
+values = ["free", "pro", "team, large"]
+weights = [0, 1, 1]
+
+[[columns]]
+name = "code"
+type = "regex"
+pattern = '[A-Z]{3}-\d{4}'
+
+[[columns]]
+name = "created_at"
+type = "timestamp"
+start = "2024-01-01"
+end = "2024-12-31T23:59:59Z"
+"#;
+
+    #[test]
+    fn csv() {
+        let schema = Schema::from_toml(SCHEMA).unwrap();
+        let mut s = String::new();
+        schema.write_csv_header(&mut s);
+        assert_eq!(s, "id,age,score,plan,code,created_at\n");
+        let mut rng = test_rng();
+        for _ in 0..100 {
+            s.clear();
+            schema.write_csv_row(&mut rng, &mut s);
+            assert!(!s.contains("free"));
+            let fields = s
+                .trim_end()
+                .replace("\"team, large\"", "team")
+                .split(',')
+                .map(String::from)
+                .collect::<Vec<_>>();
+            assert_eq!(fields.len(), 6);
+            assert_eq!(fields[0].len(), 36);
+            assert!(fields[1].is_empty() || (18..=99).contains(&fields[1].parse::<u8>().unwrap()));
+            assert_eq!(fields[2].split_once('.').unwrap().1.len(), 2);
+            assert!(["pro", "team"].contains(&fields[3].as_str()));
+            assert_eq!(fields[4].len(), 8);
+            assert!(fields[5].starts_with("2024-"));
+        }
+    }
+
+    #[test]
+    fn ndjson() {
+        let schema = Schema::from_json(
+            r#"{"columns": [{"name": "n", "type": "int", "range": "1..1"}, {"name": "s\"", "type": "enum", "values": ["a\nb"]}]}"#,
+        )
+        .unwrap();
+        let mut s = String::new();
+        schema.write_ndjson_row(&mut test_rng(), &mut s);
+        assert_eq!(s, "{\"n\":1,\"s\\\"\":\"a\\nb\"}\n");
+    }
+
+    #[test]
+    fn invalid_schema() {
+        assert!(Schema::from_json(r#"{"columns": []}"#).is_err());
+        assert!(Schema::from_toml("[[columns]]\nname = \"a\"\ntype = \"int\"").is_err());
+        assert!(
+            Schema::from_toml("[[columns]]\nname = \"a\"\ntype = \"uuid\"\nnull = 2.0").is_err()
+        );
+        assert!(Schema::from_toml("[[columns]]\nname = \"a\"\ntype = \"uuid\"\nfoo = 1").is_err());
+        assert!(
+            Schema::from_toml(
+                "[[columns]]\nname = \"a\"\ntype = \"uuid\"\n[[columns]]\nname = \"a\"\ntype = \"uuid\""
+            )
+            .is_err()
+        );
+        assert!(
+            Schema::from_toml(
+                "[[columns]]\nname = \"a\"\ntype = \"enum\"\nvalues = [\"x\"]\nweights = [1, 2]"
+            )
+            .is_err()
+        );
+        assert!(
+            Schema::from_toml(
+                "[[columns]]\nname = \"a\"\ntype = \"timestamp\"\nstart = \"2025-01-01\"\nend = \"999999999999999-01-01\""
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn timestamp() {
+        assert_eq!(parse_timestamp("1970-01-01").unwrap(), 0);
+        assert_eq!(
+            parse_timestamp("2025-01-01T00:00:00Z").unwrap(),
+            1_735_689_600
+        );
+        assert_eq!(parse_timestamp("1969-12-31T23:59:59Z").unwrap(), -1);
+        assert_eq!(parse_timestamp("2024-02-29").unwrap(), 1_709_164_800);
+        assert!(parse_timestamp("2023-02-29").is_err());
+        assert!(parse_timestamp("2025-01-01T24:00:00Z").is_err());
+        assert!(parse_timestamp("2025-01-01T00:00:00+09:00").is_err());
+        assert!(parse_timestamp("10000-01-01").is_err());
+        assert!(parse_timestamp("999999999999999-01-01").is_err());
+        assert!(parse_timestamp("0000-01-01").is_ok());
+        for seconds in [0, -1, 1_709_164_800, 1_735_689_600, 253_402_300_799] {
+            assert_eq!(
+                parse_timestamp(&format_timestamp(seconds)).unwrap(),
+                seconds
+            );
+        }
+        assert_eq!(format_timestamp(-1), "1969-12-31T23:59:59Z");
+    }
+}
@@ -0,0 +1,504 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::{fmt, str::FromStr};
+
+use anyhow::{Context, bail, ensure};
+use serde::Serialize;
+
+use crate::rng::Rng;
+
+/// The maximum number of dice which are rolled at once.
+pub const MAX_DICE: u32 = 10000;
+
+/// The maximum number of times which a die explodes.
+const MAX_EXPLOSIONS: usize = 100;
+
+/// A dice expression such as `4d6kh3+2`.
+///
+/// An expression is a sum of dice and constants. Dice are written as
+/// `[COUNT]d SIDES [!] [SELECT]`, where `SIDES` is a number, `%` (100) or `F`
+/// (Fudge dice), `!` explodes the dice which roll the maximum, and `SELECT` is
+/// one of `kh N`, `kl N`, `dh N` and `dl N`, which keep or drop the highest or
+/// lowest `N` dice.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Expression(Vec<(bool, Term)>);
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Term {
+    Dice(Dice),
+    Constant(u32),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Dice {
+    count: u32,
+    sides: Sides,
+    explode: bool,
+    select: Option<Select>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Sides {
+    Number(u32),
+    Fudge,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Select {
+    KeepHighest(u32),
+    KeepLowest(u32),
+    DropHighest(u32),
+    DropLowest(u32),
+}
+
+/// The result of rolling a dice expression.
+#[derive(Debug, Serialize)]
+pub struct Roll {
+    expression: String,
+    terms: Vec<TermRoll>,
+    total: i64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "lowercase", tag = "type")]
+enum TermRoll {
+    Dice {
+        notation: String,
+        negative: bool,
+        rolls: Vec<Die>,
+        total: i64,
+    },
+    Constant {
+        value: i64,
+    },
+}
+
+/// The result of rolling a die.
+///
+/// If the die exploded, `rolls` contains the roll and the re-rolls which are
+/// added up to `value`.
+#[derive(Debug, Serialize)]
+struct Die {
+    value: i64,
+    rolls: Vec<i64>,
+    kept: bool,
+}
+
+impl Expression {
+    /// Rolls the dice of the expression.
+    pub fn roll(&self, rng: &mut Rng) -> Roll {
+        let terms = self
+            .0
+            .iter()
+            .map(|(negative, term)| match term {
+                Term::Dice(dice) => {
+                    let rolls = dice.roll(rng);
+                    let total = rolls
+                        .iter()
+                        .filter(|die| die.kept)
+                        .map(|die| die.value)
+                        .sum::<i64>();
+                    TermRoll::Dice {
+                        notation: dice.to_string(),
+                        negative: *negative,
+                        rolls,
+                        total: if *negative { -total } else { total },
+                    }
+                }
+                Term::Constant(value) => {
+                    let value = i64::from(*value);
+                    TermRoll::Constant {
+                        value: if *negative { -value } else { value },
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+        let total = terms
+            .iter()
+            .map(|term| match term {
+                TermRoll::Dice { total, .. } => *total,
+                TermRoll::Constant { value } => *value,
+            })
+            .fold(0, i64::saturating_add);
+        Roll {
+            expression: self.to_string(),
+            terms,
+            total,
+        }
+    }
+}
+
+impl Dice {
+    fn roll(&self, rng: &mut Rng) -> Vec<Die> {
+        let (min, max) = match self.sides {
+            Sides::Number(sides) => (1, i64::from(sides)),
+            Sides::Fudge => (-1, 1),
+        };
+        let span = u64::try_from(max - min + 1).expect("number of sides should be positive");
+        let mut roll = || min + i64::try_from(rng.below(span)).expect("roll should fit in `i64`");
+
+        let mut rolls = (0..self.count)
+            .map(|_| {
+                let mut chain = vec![roll()];
+                while self.explode && chain.last() == Some(&max) && chain.len() <= MAX_EXPLOSIONS {
+                    chain.push(roll());
+                }
+                Die {
+                    value: chain.iter().sum(),
+                    rolls: chain,
+                    kept: true,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(select) = self.select {
+            let mut order = (0..rolls.len()).collect::<Vec<_>>();
+            order.sort_by_key(|&i| rolls[i].value);
+            let len = rolls.len();
+            let clamp = |n: u32| usize::try_from(n).unwrap_or(usize::MAX).min(len);
+            // The indices of the dice which are dropped in ascending order of
+            // their values.
+            let dropped = match select {
+                Select::KeepHighest(n) => &order[..len - clamp(n)],
+                Select::KeepLowest(n) => &order[clamp(n)..],
+                Select::DropHighest(n) => &order[len - clamp(n)..],
+                Select::DropLowest(n) => &order[..clamp(n)],
+            };
+            for &i in dropped {
+                rolls[i].kept = false;
+            }
+        }
+        rolls
+    }
+}
+
+impl fmt::Display for Expression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (negative, term)) in self.0.iter().enumerate() {
+            if *negative {
+                write!(f, "-")?;
+            } else if i > 0 {
+                write!(f, "+")?;
+            }
+            match term {
+                Term::Dice(dice) => write!(f, "{dice}")?,
+                Term::Constant(value) => write!(f, "{value}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Dice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}d", self.count)?;
+        match self.sides {
+            Sides::Number(sides) => write!(f, "{sides}")?,
+            Sides::Fudge => write!(f, "F")?,
+        }
+        if self.explode {
+            write!(f, "!")?;
+        }
+        match self.select {
+            Some(Select::KeepHighest(n)) => write!(f, "kh{n}"),
+            Some(Select::KeepLowest(n)) => write!(f, "kl{n}"),
+            Some(Select::DropHighest(n)) => write!(f, "dh{n}"),
+            Some(Select::DropLowest(n)) => write!(f, "dl{n}"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for Roll {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:", self.expression)?;
+        for (i, term) in self.terms.iter().enumerate() {
+            let negative = match term {
+                TermRoll::Dice { negative, .. } => *negative,
+                TermRoll::Constant { value } => *value < 0,
+            };
+            match (i, negative) {
+                (0, false) => write!(f, " ")?,
+                (0, true) => write!(f, " -")?,
+                (_, false) => write!(f, " + ")?,
+                (_, true) => write!(f, " - ")?,
+            }
+            match term {
+                TermRoll::Dice { rolls, .. } => {
+                    let rolls = rolls
+                        .iter()
+                        .map(|die| {
+                            let chain = die
+                                .rolls
+                                .iter()
+                                .map(i64::to_string)
+                                .collect::<Vec<_>>()
+                                .join("!");
+                            if die.kept {
+                                chain
+                            } else {
+                                format!("({chain})")
+                            }
+                        })
+                        .collect::<Vec<_>>();
+                    write!(f, "[{}]", rolls.join(" "))?;
+                }
+                TermRoll::Constant { value } => write!(f, "{}", value.unsigned_abs())?,
+            }
+        }
+        write!(f, " = {}", self.total)
+    }
+}
+
+/// A parser of dice expressions, which ignores whitespace between tokens.
+struct Parser<'a> {
+    s: &'a [u8],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&mut self) -> Option<u8> {
+        while self.s.get(self.pos).is_some_and(u8::is_ascii_whitespace) {
+            self.pos += 1;
+        }
+        self.s.get(self.pos).copied()
+    }
+
+    fn eat(&mut self, c: u8) -> bool {
+        let found = self.peek().is_some_and(|b| b.eq_ignore_ascii_case(&c));
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn number(&mut self) -> anyhow::Result<Option<u32>> {
+        self.peek();
+        let start = self.pos;
+        while self.s.get(self.pos).is_some_and(u8::is_ascii_digit) {
+            self.pos += 1;
+        }
+        if start == self.pos {
+            return Ok(None);
+        }
+        let digits = std::str::from_utf8(&self.s[start..self.pos]).expect("digits should be ASCII");
+        let n = digits
+            .parse()
+            .with_context(|| format!("number `{digits}` is too large"))?;
+        Ok(Some(n))
+    }
+
+    fn term(&mut self) -> anyhow::Result<Term> {
+        let count = self.number()?;
+        if !self.eat(b'd') {
+            return count
+                .map(Term::Constant)
+                .context("expected a number or dice");
+        }
+        let count = count.unwrap_or(1);
+        ensure!(
+            (1..=MAX_DICE).contains(&count),
+            "number of dice should be between 1 and {MAX_DICE}"
+        );
+
+        let sides = if self.eat(b'%') {
+            Sides::Number(100)
+        } else if self.eat(b'f') {
+            Sides::Fudge
+        } else {
+            let sides = self.number()?.context("expected the number of sides")?;
+            ensure!(sides > 0, "number of sides should be positive");
+            Sides::Number(sides)
+        };
+        let explode = self.eat(b'!');
+        ensure!(
+            !explode || sides != Sides::Number(1),
+            "exploding dice should have at least 2 sides"
+        );
+
+        let select = if self.eat(b'k') {
+            let n = if self.eat(b'l') {
+                Select::KeepLowest
+            } else {
+                self.eat(b'h');
+                Select::KeepHighest
+            };
+            Some(n(self.number()?.unwrap_or(1)))
+        } else if self.eat(b'd') {
+            let n = if self.eat(b'h') {
+                Select::DropHighest
+            } else {
+                self.eat(b'l');
+                Select::DropLowest
+            };
+            Some(n(self.number()?.unwrap_or(1)))
+        } else {
+            None
+        };
+        Ok(Term::Dice(Dice {
+            count,
+            sides,
+            explode,
+            select,
+        }))
+    }
+}
+
+impl FromStr for Expression {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            s: s.as_bytes(),
+            pos: 0,
+        };
+        let mut terms = Vec::new();
+        let mut negative = parser.eat(b'-');
+        loop {
+            terms.push((negative, parser.term()?));
+            if parser.eat(b'+') {
+                negative = false;
+            } else if parser.eat(b'-') {
+                negative = true;
+            } else {
+                break;
+            }
+        }
+        if let Some(c) = parser.peek() {
+            bail!("unexpected character `{}`", char::from(c));
+        }
+        Ok(Self(terms))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::test_rng;
+
+    #[test]
+    fn parse() {
+        for (expression, expected) in [
+            ("4d6kh3", "4d6kh3"),
+            ("d20 + 5", "1d20+5"),
+            ("-2D8!k - 1", "-2d8!kh1-1"),
+            ("3d%dl", "3d100dl1"),
+            ("4dF", "4dF"),
+            ("2d6d1", "2d6dl1"),
+            ("5d10kl2+1d4dh1", "5d10kl2+1d4dh1"),
+            ("7", "7"),
+        ] {
+            assert_eq!(
+                expression.parse::<Expression>().unwrap().to_string(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn parse_invalid_expression() {
+        for expression in [
+            "", "d", "4d", "0d6", "10001d6", "1d0", "1d1!", "4d6kx", "1d6+", "1d6 2", "d6 * 2",
+        ] {
+            assert!(expression.parse::<Expression>().is_err(), "{expression}");
+        }
+    }
+
+    #[test]
+    fn roll() {
+        let mut rng = test_rng();
+        for _ in 0..1000 {
+            let roll = "4d6kh3+2".parse::<Expression>().unwrap().roll(&mut rng);
+            let TermRoll::Dice { rolls, total, .. } = &roll.terms[0] else {
+                unreachable!();
+            };
+            assert_eq!(rolls.len(), 4);
+            assert_eq!(rolls.iter().filter(|die| die.kept).count(), 3);
+            let min = rolls.iter().map(|die| die.value).min().unwrap();
+            assert_eq!(*total, rolls.iter().map(|die| die.value).sum::<i64>() - min);
+            assert_eq!(roll.total, total + 2);
+            assert!((5..=20).contains(&roll.total));
+        }
+    }
+
+    #[test]
+    fn roll_fudge_dice() {
+        let mut rng = test_rng();
+        for _ in 0..100 {
+            let roll = "4dF".parse::<Expression>().unwrap().roll(&mut rng);
+            assert!((-4..=4).contains(&roll.total));
+        }
+    }
+
+    #[test]
+    fn exploding_dice() {
+        let mut rng = test_rng();
+        let roll = "1000d2!".parse::<Expression>().unwrap().roll(&mut rng);
+        let TermRoll::Dice { rolls, total, .. } = &roll.terms[0] else {
+            unreachable!();
+        };
+        assert_eq!(rolls.len(), 1000);
+        assert!(rolls.iter().any(|die| die.rolls.len() > 1));
+        for die in rolls {
+            let (last, exploded) = die.rolls.split_last().unwrap();
+            assert_eq!(*last, 1);
+            assert!(exploded.iter().all(|&value| value == 2));
+            assert_eq!(die.value, die.rolls.iter().sum::<i64>());
+        }
+        assert_eq!(*total, rolls.iter().map(|die| die.value).sum::<i64>());
+    }
+
+    #[test]
+    fn keep_exploding_dice() {
+        let mut rng = test_rng();
+        for _ in 0..1000 {
+            let roll = "2d6!kh1".parse::<Expression>().unwrap().roll(&mut rng);
+            let TermRoll::Dice { rolls, total, .. } = &roll.terms[0] else {
+                unreachable!();
+            };
+            assert_eq!(rolls.len(), 2);
+            let max = rolls.iter().map(|die| die.value).max().unwrap();
+            assert_eq!(*total, max);
+            assert_eq!(
+                rolls.iter().find(|die| die.kept).map(|die| die.value),
+                Some(max)
+            );
+        }
+    }
+
+    #[test]
+    fn display() {
+        let roll = Roll {
+            expression: "3d6!dl1-1".into(),
+            terms: vec![
+                TermRoll::Dice {
+                    notation: "3d6!dl1".into(),
+                    negative: false,
+                    rolls: vec![
+                        Die {
+                            value: 8,
+                            rolls: vec![6, 2],
+                            kept: true,
+                        },
+                        Die {
+                            value: 4,
+                            rolls: vec![4],
+                            kept: true,
+                        },
+                        Die {
+                            value: 1,
+                            rolls: vec![1],
+                            kept: false,
+                        },
+                    ],
+                    total: 12,
+                },
+                TermRoll::Constant { value: -1 },
+            ],
+            total: 11,
+        };
+        assert_eq!(roll.to_string(), "3d6!dl1-1: [6!2 4 (1)] - 1 = 11");
+    }
+}
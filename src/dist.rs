@@ -0,0 +1,109 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::str::FromStr;
+
+use anyhow::{Context, bail};
+use rand_distr::{Beta, Binomial, Distribution, Exp, Gamma, Normal, Poisson};
+
+use crate::rng::Rng;
+
+/// A probability distribution to draw samples from.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Dist {
+    Uniform,
+    Normal(Normal<f64>),
+    Exp(Exp<f64>),
+    Gamma(Gamma<f64>),
+    Beta(Beta<f64>),
+    Poisson(Poisson<f64>),
+    Binomial(Binomial),
+}
+
+impl Dist {
+    /// Returns a random sample from the distribution.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn sample(&self, rng: &mut Rng) -> f64 {
+        match self {
+            Self::Uniform => rng.next_f64(),
+            Self::Normal(dist) => dist.sample(rng),
+            Self::Exp(dist) => dist.sample(rng),
+            Self::Gamma(dist) => dist.sample(rng),
+            Self::Beta(dist) => dist.sample(rng),
+            Self::Poisson(dist) => dist.sample(rng),
+            Self::Binomial(dist) => dist.sample(rng) as f64,
+        }
+    }
+}
+
+impl FromStr for Dist {
+    type Err = anyhow::Error;
+
+    #[allow(clippy::cast_precision_loss)]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, params) = s.split_once(':').unwrap_or((s, ""));
+        let params = if params.is_empty() {
+            Vec::new()
+        } else {
+            params
+                .split(',')
+                .map(|p| {
+                    p.trim()
+                        .parse::<f64>()
+                        .with_context(|| format!("invalid parameter `{p}`"))
+                })
+                .collect::<anyhow::Result<_>>()?
+        };
+        let dist = match (name.to_ascii_lowercase().as_str(), params.as_slice()) {
+            ("uniform", []) => Self::Uniform,
+            ("normal", []) => Self::Normal(Normal::new(0.0, 1.0)?),
+            ("normal", &[mean, std_dev]) => Self::Normal(Normal::new(mean, std_dev)?),
+            ("exp", []) => Self::Exp(Exp::new(1.0)?),
+            ("exp", &[lambda]) => Self::Exp(Exp::new(lambda)?),
+            ("gamma", &[shape, scale]) => Self::Gamma(Gamma::new(shape, scale)?),
+            ("beta", &[alpha, beta]) => Self::Beta(Beta::new(alpha, beta)?),
+            ("poisson", &[lambda]) => Self::Poisson(Poisson::new(lambda)?),
+            ("binomial", &[n, p]) => {
+                if n.fract() != 0.0 || !(0.0..u64::MAX as f64).contains(&n) {
+                    bail!("number of trials should be a non-negative integer");
+                }
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                Self::Binomial(Binomial::new(n as u64, p)?)
+            }
+            ("uniform" | "normal" | "exp" | "gamma" | "beta" | "poisson" | "binomial", _) => {
+                bail!("wrong number of parameters for `{name}`")
+            }
+            _ => bail!("unknown distribution `{name}`"),
+        };
+        Ok(dist)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_dist() {
+        assert_eq!("uniform".parse::<Dist>().unwrap(), Dist::Uniform);
+        assert_eq!(
+            "normal".parse::<Dist>().unwrap(),
+            Dist::Normal(Normal::new(0.0, 1.0).unwrap())
+        );
+        assert_eq!(
+            "Normal:10,2.5".parse::<Dist>().unwrap(),
+            Dist::Normal(Normal::new(10.0, 2.5).unwrap())
+        );
+        assert_eq!(
+            "binomial:10,0.5".parse::<Dist>().unwrap(),
+            Dist::Binomial(Binomial::new(10, 0.5).unwrap())
+        );
+        assert!("normal:1".parse::<Dist>().is_err());
+        assert!("normal:0,inf".parse::<Dist>().is_err());
+        assert!("gamma".parse::<Dist>().is_err());
+        assert!("binomial:1.5,0.5".parse::<Dist>().is_err());
+        assert!("binomial:18446744073709551616,0.5".parse::<Dist>().is_err());
+        assert!("cauchy".parse::<Dist>().is_err());
+    }
+}
@@ -0,0 +1,209 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::collections::HashSet;
+
+use anyhow::{Context, ensure};
+use rand_core::RngCore;
+
+use crate::rng::Rng;
+
+/// The Crockford's Base32 alphabet.
+const CROCKFORD_BASE32: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// The base32 alphabet defined in RFC 4648.
+pub const BASE32: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// The base62 alphabet, which is used by KSUID.
+pub const BASE62: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// The default alphabet of Nano ID.
+pub const NANOID_ALPHABET: &str =
+    "_-0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// The epoch of KSUID timestamps in seconds since the Unix epoch.
+const KSUID_EPOCH: u64 = 1_400_000_000;
+
+/// A generator of ULIDs.
+#[derive(Debug)]
+pub struct Ulid {
+    monotonic: bool,
+    last: Option<(u64, u128)>,
+}
+
+impl Ulid {
+    /// The maximum value of the random component.
+    const MAX_RANDOM: u128 = (1 << 80) - 1;
+
+    /// Creates a new `Ulid`.
+    ///
+    /// If `monotonic` is `true`, the random component of a ULID which has the
+    /// same timestamp as the previous one is the previous one plus one.
+    pub const fn new(monotonic: bool) -> Self {
+        Self {
+            monotonic,
+            last: None,
+        }
+    }
+
+    /// Generates a ULID with a Unix timestamp in milliseconds.
+    pub fn generate(&mut self, rng: &mut Rng, timestamp: u64) -> anyhow::Result<String> {
+        ensure!(
+            timestamp < 1 << 48,
+            "timestamp {timestamp} does not fit in 48 bits"
+        );
+        let random = match self.last {
+            Some((last, random)) if self.monotonic && last == timestamp => {
+                ensure!(
+                    random < Self::MAX_RANDOM,
+                    "random component of ULID overflowed within the same millisecond"
+                );
+                random + 1
+            }
+            _ => rng.next_u128() & Self::MAX_RANDOM,
+        };
+        self.last = Some((timestamp, random));
+        let value = (u128::from(timestamp) << 80) | random;
+        Ok((0..26)
+            .map(|i| {
+                let index = usize::try_from((value >> (125 - 5 * i)) & 0x1f)
+                    .expect("index should be less than 32");
+                char::from(CROCKFORD_BASE32[index])
+            })
+            .collect())
+    }
+}
+
+/// Generates a KSUID with a Unix timestamp in milliseconds.
+///
+/// The timestamp is truncated to seconds.
+pub fn ksuid(rng: &mut Rng, timestamp: u64) -> anyhow::Result<String> {
+    let timestamp = (timestamp / 1000)
+        .checked_sub(KSUID_EPOCH)
+        .and_then(|t| u32::try_from(t).ok())
+        .with_context(|| {
+            format!("timestamp {timestamp} is out of the range of KSUID timestamps")
+        })?;
+    let mut bytes = [u8::MIN; 20];
+    bytes[..4].copy_from_slice(&timestamp.to_be_bytes());
+    rng.fill_bytes(&mut bytes[4..]);
+    Ok(base62(bytes))
+}
+
+/// Encodes 20 bytes as a 160-bit big-endian integer in 27 base62 digits.
+fn base62(mut bytes: [u8; 20]) -> String {
+    // Repeatedly divides the integer by 62 from the least significant digit.
+    let mut digits = [b'0'; 27];
+    for digit in digits.iter_mut().rev() {
+        let mut remainder = 0;
+        for byte in &mut bytes {
+            let acc = (remainder << 8) | u32::from(*byte);
+            *byte = u8::try_from(acc / 62).expect("quotient should fit in `u8`");
+            remainder = acc % 62;
+        }
+        *digit = BASE62[usize::try_from(remainder).expect("remainder should fit in `usize`")];
+    }
+    digits.into_iter().map(char::from).collect()
+}
+
+/// Generates a Nano ID which consists of `size` characters of `alphabet`.
+pub fn nanoid(rng: &mut Rng, alphabet: &[char], size: usize) -> String {
+    let len = u64::try_from(alphabet.len()).expect("alphabet should be small");
+    (0..size)
+        .map(|_| alphabet[usize::try_from(rng.below(len)).expect("index should fit in `usize`")])
+        .collect()
+}
+
+/// Returns the least size of a Nano ID which gives at least `bits` of entropy
+/// with `alphabet_len` characters.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+pub fn nanoid_size(bits: u32, alphabet_len: usize) -> usize {
+    (f64::from(bits) / (alphabet_len as f64).log2()).ceil() as usize
+}
+
+/// Parses an alphabet of Nano ID.
+pub fn parse_alphabet(s: &str) -> anyhow::Result<String> {
+    let mut seen = HashSet::new();
+    ensure!(
+        s.chars().all(|c| seen.insert(c)),
+        "alphabet should not contain duplicate characters"
+    );
+    ensure!(
+        seen.len() >= 2,
+        "alphabet should contain at least 2 characters"
+    );
+    Ok(s.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::test_rng;
+
+    #[test]
+    fn ulid() {
+        let mut rng = test_rng();
+        let mut ulid = Ulid::new(false);
+        let id = ulid.generate(&mut rng, 1_469_922_850_259).unwrap();
+        assert_eq!(id.len(), 26);
+        assert!(id.starts_with("01ARZ3NDEK"));
+        assert!(ulid.generate(&mut rng, 1 << 48).is_err());
+    }
+
+    #[test]
+    fn monotonic_ulid() {
+        let mut rng = test_rng();
+        let mut ulid = Ulid::new(true);
+        let first = ulid.generate(&mut rng, 0).unwrap();
+        let second = ulid.generate(&mut rng, 0).unwrap();
+        assert!(first < second);
+        assert_eq!(first[..25], second[..25]);
+        ulid.last = Some((0, Ulid::MAX_RANDOM));
+        assert!(ulid.generate(&mut rng, 0).is_err());
+    }
+
+    #[test]
+    fn ksuid() {
+        let id = super::ksuid(&mut test_rng(), KSUID_EPOCH * 1000).unwrap();
+        assert_eq!(id.len(), 27);
+        assert!(super::ksuid(&mut test_rng(), 0).is_err());
+    }
+
+    #[test]
+    fn base62() {
+        assert_eq!(super::base62([u8::MIN; 20]), "0".repeat(27));
+        assert_eq!(super::base62([u8::MAX; 20]), "aWgEPTl1tmebfsQzFP4bxwgy80V");
+        let bytes = [
+            0x06, 0x69, 0xf7, 0xef, 0xb5, 0xa1, 0xcd, 0x34, 0xb5, 0xf9, 0x9d, 0x11, 0x54, 0xfb,
+            0x68, 0x53, 0x34, 0x5c, 0x97, 0x35,
+        ];
+        assert_eq!(super::base62(bytes), "0ujtsYcgvSTl8PAuAdqWYSMnLOv");
+    }
+
+    #[test]
+    fn nanoid() {
+        let alphabet = NANOID_ALPHABET.chars().collect::<Vec<_>>();
+        let id = super::nanoid(&mut test_rng(), &alphabet, 21);
+        assert_eq!(id.chars().count(), 21);
+        assert!(id.chars().all(|c| NANOID_ALPHABET.contains(c)));
+    }
+
+    #[test]
+    fn nanoid_size() {
+        assert_eq!(super::nanoid_size(126, 64), 21);
+        assert_eq!(super::nanoid_size(127, 64), 22);
+        assert_eq!(super::nanoid_size(128, 16), 32);
+    }
+
+    #[test]
+    fn parse_alphabet() {
+        assert_eq!(super::parse_alphabet("01").unwrap(), "01");
+        assert!(super::parse_alphabet("0").is_err());
+        assert!(super::parse_alphabet("010").is_err());
+    }
+}
@@ -0,0 +1,146 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::{fmt, str::FromStr};
+
+use anyhow::{Context, bail};
+
+use crate::rng::Rng;
+
+/// An inclusive range of integers.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IntRange {
+    Signed { min: i128, max: i128 },
+    Unsigned { min: u128, max: u128 },
+}
+
+impl IntRange {
+    /// Returns the number of integers in the range minus one.
+    pub const fn span(&self) -> u128 {
+        match self {
+            Self::Signed { min, max } => max.abs_diff(*min),
+            Self::Unsigned { min, max } => *max - *min,
+        }
+    }
+
+    /// Returns a uniformly distributed random integer in the range.
+    pub fn sample(&self, rng: &mut Rng) -> Int {
+        let span = self.span();
+        let offset = if span == u128::MAX {
+            rng.next_u128()
+        } else {
+            rng.below_u128(span + 1)
+        };
+        self.nth(offset)
+    }
+
+    /// Returns the `n`th integer in the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than [`IntRange::span`].
+    pub fn nth(&self, n: u128) -> Int {
+        assert!(n <= self.span(), "`n` should be in the range");
+        match self {
+            Self::Signed { min, .. } => Int::Signed(
+                min.checked_add_unsigned(n)
+                    .expect("integer should be in the range"),
+            ),
+            Self::Unsigned { min, .. } => Int::Unsigned(*min + n),
+        }
+    }
+}
+
+impl FromStr for IntRange {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (min, max) = s
+            .split_once("..")
+            .context("range should be in the form `MIN..MAX`")?;
+        let max = max.strip_prefix('=').unwrap_or(max);
+        let range = if let (Ok(min), Ok(max)) = (min.parse(), max.parse()) {
+            Self::Signed { min, max }
+        } else {
+            let min = min
+                .parse()
+                .with_context(|| format!("invalid minimum value `{min}`"))?;
+            let max = max
+                .parse()
+                .with_context(|| format!("invalid maximum value `{max}`"))?;
+            Self::Unsigned { min, max }
+        };
+        let is_valid = match range {
+            Self::Signed { min, max } => min <= max,
+            Self::Unsigned { min, max } => min <= max,
+        };
+        if !is_valid {
+            bail!("minimum value should be less than or equal to maximum value");
+        }
+        Ok(range)
+    }
+}
+
+/// A signed or unsigned integer.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Int {
+    Signed(i128),
+    Unsigned(u128),
+}
+
+impl fmt::Display for Int {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Signed(n) => write!(f, "{n}"),
+            Self::Unsigned(n) => write!(f, "{n}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_int_range() {
+        assert_eq!(
+            "1..6".parse::<IntRange>().unwrap(),
+            IntRange::Signed { min: 1, max: 6 }
+        );
+        assert_eq!(
+            "-8..=-2".parse::<IntRange>().unwrap(),
+            IntRange::Signed { min: -8, max: -2 }
+        );
+        assert_eq!(
+            "0..340282366920938463463374607431768211455"
+                .parse::<IntRange>()
+                .unwrap(),
+            IntRange::Unsigned {
+                min: u128::MIN,
+                max: u128::MAX
+            }
+        );
+        assert!("6..1".parse::<IntRange>().is_err());
+        assert!("1-6".parse::<IntRange>().is_err());
+        assert!("a..6".parse::<IntRange>().is_err());
+        assert!(
+            "-1..340282366920938463463374607431768211455"
+                .parse::<IntRange>()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn span() {
+        assert_eq!(IntRange::Signed { min: 1, max: 6 }.span(), 5);
+        assert_eq!(
+            IntRange::Signed {
+                min: i128::MIN,
+                max: i128::MAX
+            }
+            .span(),
+            u128::MAX
+        );
+    }
+}
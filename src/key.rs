@@ -0,0 +1,146 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::num::NonZeroUsize;
+
+use anyhow::bail;
+use base64::Engine;
+use serde::Serialize;
+
+use crate::cli::{JwkAlgorithm, value_name};
+
+/// The number of bytes of an X25519 private key.
+pub const X25519_KEY_LEN: usize = 32;
+
+/// The number of bytes of a JWK if neither the length nor the algorithm is
+/// specified.
+const DEFAULT_JWK_LEN: usize = 32;
+
+/// A JSON Web Key of the symmetric key type, which is defined in RFC 7518.
+#[derive(Debug, Serialize)]
+pub struct Jwk<'a> {
+    kty: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    alg: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    kid: Option<&'a str>,
+    k: String,
+}
+
+impl<'a> Jwk<'a> {
+    /// Creates a new `Jwk` of `key`.
+    pub fn new(key: &[u8], alg: Option<JwkAlgorithm>, kid: Option<&'a str>) -> Self {
+        Self {
+            kty: "oct",
+            alg: alg.map(|alg| value_name(&alg)),
+            kid,
+            k: base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(key),
+        }
+    }
+}
+
+/// Returns the number of bytes of a JWK.
+///
+/// # Errors
+///
+/// Returns [`Err`] if `len` is not allowed for `alg`.
+pub fn jwk_len(alg: Option<JwkAlgorithm>, len: Option<NonZeroUsize>) -> anyhow::Result<usize> {
+    let len = len.map(NonZeroUsize::get);
+    let Some(alg) = alg else {
+        return Ok(len.unwrap_or(DEFAULT_JWK_LEN));
+    };
+    let required = alg.key_len();
+    match len {
+        None => Ok(required),
+        Some(len) if len == required || (alg.is_hmac() && len > required) => Ok(len),
+        Some(len) if alg.is_hmac() => bail!(
+            "{} requires a key of at least {required} bytes, but {len} bytes were specified",
+            value_name(&alg)
+        ),
+        Some(len) => bail!(
+            "{} requires a key of {required} bytes, but {len} bytes were specified",
+            value_name(&alg)
+        ),
+    }
+}
+
+/// Encodes `key` as a private key of `WireGuard`.
+///
+/// The key is clamped as an X25519 private key, and then encoded as base64.
+pub fn wireguard(mut key: [u8; X25519_KEY_LEN]) -> String {
+    key[0] &= 0b1111_1000;
+    key[31] &= 0b0111_1111;
+    key[31] |= 0b0100_0000;
+    base64::engine::general_purpose::STANDARD.encode(key)
+}
+
+/// Encodes `key` as an age secret key.
+///
+/// This is the uppercase Bech32 string whose human-readable part is
+/// `AGE-SECRET-KEY-`.
+#[cfg(feature = "bech32")]
+pub fn age(key: &[u8; X25519_KEY_LEN]) -> String {
+    const HRP: bech32::Hrp = bech32::Hrp::parse_unchecked("age-secret-key-");
+    bech32::encode_upper::<bech32::Bech32>(HRP, key).expect("age secret key should not be too long")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn jwk() {
+        let key = [0xfb; 16];
+        assert_eq!(
+            serde_json::to_string(&Jwk::new(&key, None, None)).unwrap(),
+            r#"{"kty":"oct","k":"-_v7-_v7-_v7-_v7-_v7-w"}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&Jwk::new(&key, Some(JwkAlgorithm::A128Gcm), Some("a"))).unwrap(),
+            r#"{"kty":"oct","alg":"A128GCM","kid":"a","k":"-_v7-_v7-_v7-_v7-_v7-w"}"#
+        );
+    }
+
+    #[test]
+    fn jwk_len() {
+        let len = |len| NonZeroUsize::new(len);
+        assert_eq!(super::jwk_len(None, None).unwrap(), 32);
+        assert_eq!(super::jwk_len(None, len(7)).unwrap(), 7);
+        assert_eq!(super::jwk_len(Some(JwkAlgorithm::Hs384), None).unwrap(), 48);
+        assert_eq!(
+            super::jwk_len(Some(JwkAlgorithm::Hs256), len(64)).unwrap(),
+            64
+        );
+        assert!(super::jwk_len(Some(JwkAlgorithm::Hs256), len(16)).is_err());
+        assert_eq!(
+            super::jwk_len(Some(JwkAlgorithm::A128Kw), len(16)).unwrap(),
+            16
+        );
+        assert!(super::jwk_len(Some(JwkAlgorithm::A128Kw), len(32)).is_err());
+    }
+
+    #[test]
+    fn wireguard() {
+        assert_eq!(
+            super::wireguard([u8::MAX; X25519_KEY_LEN]),
+            "+P///////////////////////////////////////38="
+        );
+        assert_eq!(
+            super::wireguard([u8::MIN; X25519_KEY_LEN]),
+            "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAEA="
+        );
+    }
+
+    #[cfg(feature = "bech32")]
+    #[test]
+    fn age() {
+        let key = [0x42; X25519_KEY_LEN];
+        let encoded = super::age(&key);
+        assert!(encoded.starts_with("AGE-SECRET-KEY-1"));
+        assert_eq!(encoded.len(), 74);
+        let (hrp, data) = bech32::decode(&encoded).unwrap();
+        assert_eq!(hrp.as_str(), "AGE-SECRET-KEY-");
+        assert_eq!(data, key);
+    }
+}
@@ -0,0 +1,217 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::{fmt::Write, num::NonZeroUsize};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Language {
+    C,
+    Go,
+    JavaScript,
+    Python,
+    Rust,
+}
+
+impl Language {
+    /// Returns the identifier used when no name is specified.
+    pub const fn default_name(self) -> &'static str {
+        match self {
+            Self::C | Self::Python => "random_bytes",
+            Self::Go | Self::JavaScript => "randomBytes",
+            Self::Rust => "RANDOM_BYTES",
+        }
+    }
+
+    const fn indent(self) -> &'static str {
+        match self {
+            Self::C | Self::Python | Self::Rust => "    ",
+            Self::Go => "\t",
+            Self::JavaScript => "  ",
+        }
+    }
+}
+
+/// A source code literal which represents a byte array.
+#[derive(Debug)]
+pub struct Literal<'a> {
+    language: Language,
+    name: &'a str,
+    items_per_line: usize,
+    len: usize,
+}
+
+impl<'a> Literal<'a> {
+    /// Creates a new `Literal` for `len` bytes.
+    pub const fn new(
+        language: Language,
+        name: &'a str,
+        items_per_line: NonZeroUsize,
+        len: usize,
+    ) -> Self {
+        Self {
+            language,
+            name,
+            items_per_line: items_per_line.get(),
+            len,
+        }
+    }
+
+    /// Returns the text which precedes the items.
+    pub fn header(&self) -> String {
+        let (name, len) = (self.name, self.len);
+        match self.language {
+            Language::C => format!("const uint8_t {name}[{len}] = {{\n"),
+            Language::Go => format!("var {name} = []byte{{\n"),
+            Language::JavaScript => format!("const {name} = new Uint8Array([\n"),
+            Language::Python => format!("{name} = (\n"),
+            Language::Rust => format!("const {name}: [u8; {len}] = [\n"),
+        }
+    }
+
+    /// Returns the text which follows the items.
+    pub fn footer(&self) -> String {
+        match self.language {
+            Language::C => "};\n".into(),
+            Language::Go => "}\n".into(),
+            Language::JavaScript => "]);\n".into(),
+            // An empty parenthesized expression is a tuple, not a bytes object.
+            Language::Python if self.len == 0 => format!("{}b\"\"\n)\n", Language::Python.indent()),
+            Language::Python => ")\n".into(),
+            Language::Rust => "];\n".into(),
+        }
+    }
+
+    /// Returns the number of bytes of the whole literal.
+    ///
+    /// Returns [`None`] if the length overflows.
+    pub fn encoded_len(&self) -> Option<usize> {
+        let lines = self.len.div_ceil(self.items_per_line);
+        let indent = self.language.indent().len();
+        let body = match self.language {
+            // `\xNN` for each byte, and an indent, `b"` and `"\n` for each line.
+            Language::Python => self
+                .len
+                .checked_mul(4)?
+                .checked_add(lines.checked_mul(indent + 4)?)?,
+            // `0xNN` and either `, ` or `,\n` for each byte, and an indent for each
+            // line.
+            _ => self
+                .len
+                .checked_mul(6)?
+                .checked_add(lines.checked_mul(indent)?)?,
+        };
+        body.checked_add(self.header().len())?
+            .checked_add(self.footer().len())
+    }
+
+    /// Encodes `bytes` as the items of the literal.
+    ///
+    /// `offset` is the position of the first byte of `bytes` in the whole
+    /// literal.
+    pub fn encode(&self, bytes: &[u8], offset: usize) -> String {
+        let indent = self.language.indent();
+        let mut s = String::with_capacity(bytes.len() * 8);
+        for (i, byte) in (offset..).zip(bytes) {
+            let is_first = i % self.items_per_line == 0;
+            let is_last = (i + 1) % self.items_per_line == 0 || i + 1 == self.len;
+            if self.language == Language::Python {
+                if is_first {
+                    s.push_str(indent);
+                    s.push_str("b\"");
+                }
+                write!(s, "\\x{byte:02x}").expect("writing to a string should not fail");
+                if is_last {
+                    s.push_str("\"\n");
+                }
+            } else {
+                if is_first {
+                    s.push_str(indent);
+                }
+                write!(s, "0x{byte:02x}").expect("writing to a string should not fail");
+                s.push_str(if is_last { ",\n" } else { ", " });
+            }
+        }
+        s
+    }
+}
+
+/// Returns [`Ok`] if `s` is a valid identifier in all supported languages.
+pub fn parse_identifier(s: &str) -> Result<String, String> {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {}
+        Some(_) => return Err(String::from("identifier must start with a letter or `_`")),
+        None => return Err(String::from("identifier must not be empty")),
+    }
+    if chars.all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        Ok(s.into())
+    } else {
+        Err(String::from(
+            "identifier must consist of letters, digits and `_`",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_literal(language: Language, items_per_line: usize, len: usize) -> Literal<'static> {
+        Literal::new(
+            language,
+            language.default_name(),
+            NonZeroUsize::new(items_per_line).unwrap(),
+            len,
+        )
+    }
+
+    fn render(literal: &Literal<'_>, bytes: &[u8]) -> String {
+        let (first, second) = bytes.split_at(bytes.len() / 2);
+        literal.header()
+            + &literal.encode(first, 0)
+            + &literal.encode(second, first.len())
+            + &literal.footer()
+    }
+
+    #[test]
+    fn encoded_len() {
+        let bytes = [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd];
+        for language in [
+            Language::C,
+            Language::Go,
+            Language::JavaScript,
+            Language::Python,
+            Language::Rust,
+        ] {
+            for items_per_line in 1..=8 {
+                for len in 0..=bytes.len() {
+                    let literal = new_literal(language, items_per_line, len);
+                    assert_eq!(
+                        literal.encoded_len(),
+                        Some(render(&literal, &bytes[..len]).len())
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn render_python() {
+        let literal = new_literal(Language::Python, 2, 3);
+        assert_eq!(
+            render(&literal, &[0x00, 0x7f, 0xff]),
+            "random_bytes = (\n    b\"\\x00\\x7f\"\n    b\"\\xff\"\n)\n"
+        );
+        let literal = new_literal(Language::Python, 2, 0);
+        assert_eq!(render(&literal, &[]), "random_bytes = (\n    b\"\"\n)\n");
+    }
+
+    #[test]
+    fn parse_identifier() {
+        assert_eq!(super::parse_identifier("_key1"), Ok(String::from("_key1")));
+        assert!(super::parse_identifier("").is_err());
+        assert!(super::parse_identifier("1key").is_err());
+        assert!(super::parse_identifier("key-1").is_err());
+    }
+}
@@ -3,8 +3,32 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 mod app;
+mod bigint;
+mod cards;
+mod choose;
 mod cli;
+mod clock;
+mod dataset;
+mod dice;
+mod dist;
+mod id;
+mod int;
+#[cfg(feature = "base64")]
+mod key;
+mod literal;
+mod net;
+mod passphrase;
+mod password;
+mod permutation;
+mod pronounceable;
+mod radix;
+mod regex;
 mod rng;
+mod sample;
+mod shuffle;
+mod template;
+mod unique;
+mod uuid;
 
 use std::{io, process::ExitCode};
 
@@ -0,0 +1,265 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::{
+    fmt,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    str::FromStr,
+};
+
+use anyhow::{Context, bail};
+
+use crate::int::IntRange;
+
+/// The number of random bits of a locally administered unicast MAC address.
+const MAC_RANDOM_BITS: u32 = 46;
+
+/// A MAC address.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MacAddress([u8; 6]);
+
+impl MacAddress {
+    /// Returns the range of the indices of the locally administered unicast
+    /// MAC addresses.
+    pub const fn range() -> IntRange {
+        IntRange::Unsigned {
+            min: u128::MIN,
+            max: (1 << MAC_RANDOM_BITS) - 1,
+        }
+    }
+
+    /// Returns the `n`th locally administered unicast MAC address.
+    ///
+    /// In the first octet, the I/G bit is cleared and the U/L bit is set, and
+    /// the other 46 bits are taken from `n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is not in [`MacAddress::range`].
+    pub fn nth(n: u128) -> Self {
+        assert!(n >> MAC_RANDOM_BITS == 0, "`n` should be in the range");
+        let mut octets = [u8::MIN; 6];
+        octets.copy_from_slice(&n.to_be_bytes()[10..]);
+        octets[0] = (octets[0] << 2) | 0b10;
+        Self(octets)
+    }
+}
+
+impl fmt::Display for MacAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, octet) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ":")?;
+            }
+            write!(f, "{octet:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A block of IPv4 or IPv6 addresses in CIDR notation.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cidr {
+    network: IpAddr,
+    prefix: u32,
+}
+
+impl Cidr {
+    /// Returns the range of the offsets of the addresses in the block.
+    ///
+    /// If `hosts_only` is `true`, the network address and the broadcast
+    /// address of an IPv4 block and the Subnet-Router anycast address of an
+    /// IPv6 block are excluded. A block of at most 2 addresses is regarded as a
+    /// point-to-point link, so nothing is excluded from it.
+    pub fn offsets(&self, hosts_only: bool) -> IntRange {
+        let bits = self.host_bits();
+        let max = u128::MAX.checked_shr(u128::BITS - bits).unwrap_or_default();
+        match self.network {
+            _ if !hosts_only || bits <= 1 => IntRange::Unsigned { min: 0, max },
+            IpAddr::V4(_) => IntRange::Unsigned {
+                min: 1,
+                max: max - 1,
+            },
+            IpAddr::V6(_) => IntRange::Unsigned { min: 1, max },
+        }
+    }
+
+    /// Returns the address at `offset` in the block.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset` is out of the block.
+    pub fn nth(&self, offset: u128) -> IpAddr {
+        assert!(
+            offset.checked_shr(self.host_bits()).unwrap_or_default() == 0,
+            "`offset` should be in the block"
+        );
+        match self.network {
+            IpAddr::V4(network) => {
+                let offset = u32::try_from(offset).expect("offset should fit in `u32`");
+                Ipv4Addr::from(u32::from(network) | offset).into()
+            }
+            IpAddr::V6(network) => Ipv6Addr::from(u128::from(network) | offset).into(),
+        }
+    }
+
+    const fn host_bits(&self) -> u32 {
+        address_bits(self.network) - self.prefix
+    }
+}
+
+impl FromStr for Cidr {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (addr, prefix) = s
+            .split_once('/')
+            .context("CIDR block should be in the form `ADDRESS/PREFIX`")?;
+        let addr = addr
+            .parse::<IpAddr>()
+            .with_context(|| format!("invalid IP address `{addr}`"))?;
+        let prefix = prefix
+            .parse::<u32>()
+            .with_context(|| format!("invalid prefix length `{prefix}`"))?;
+        let max_prefix = address_bits(addr);
+        if prefix > max_prefix {
+            bail!("prefix length should be at most {max_prefix}");
+        }
+        let network = match addr {
+            IpAddr::V4(addr) => {
+                let mask = u32::MAX.checked_shl(max_prefix - prefix);
+                Ipv4Addr::from(u32::from(addr) & mask.unwrap_or_default()).into()
+            }
+            IpAddr::V6(addr) => {
+                let mask = u128::MAX.checked_shl(max_prefix - prefix);
+                Ipv6Addr::from(u128::from(addr) & mask.unwrap_or_default()).into()
+            }
+        };
+        if network != addr {
+            bail!("`{addr}` has host bits set; the network address is `{network}/{prefix}`");
+        }
+        Ok(Self { network, prefix })
+    }
+}
+
+/// Returns the number of bits of `addr`.
+const fn address_bits(addr: IpAddr) -> u32 {
+    match addr {
+        IpAddr::V4(_) => Ipv4Addr::BITS,
+        IpAddr::V6(_) => Ipv6Addr::BITS,
+    }
+}
+
+/// Parses a range of port numbers of the form `MIN..MAX`.
+pub fn parse_port_range(s: &str) -> anyhow::Result<IntRange> {
+    let range = s.parse::<IntRange>()?;
+    match range {
+        IntRange::Signed { min, max } if min >= 0 && max <= i128::from(u16::MAX) => {
+            Ok(IntRange::Unsigned {
+                min: min.unsigned_abs(),
+                max: max.unsigned_abs(),
+            })
+        }
+        _ => bail!("port numbers should be between 0 and 65535"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mac_address() {
+        assert_eq!(MacAddress::nth(0).to_string(), "02:00:00:00:00:00");
+        assert_eq!(
+            MacAddress::nth(0x1234_5678_9abc).to_string(),
+            "4a:34:56:78:9a:bc"
+        );
+        let IntRange::Unsigned { max, .. } = MacAddress::range() else {
+            unreachable!();
+        };
+        assert_eq!(MacAddress::nth(max).to_string(), "fe:ff:ff:ff:ff:ff");
+    }
+
+    #[test]
+    #[should_panic(expected = "`n` should be in the range")]
+    fn mac_address_out_of_range() {
+        MacAddress::nth(1 << MAC_RANDOM_BITS);
+    }
+
+    #[test]
+    fn parse_cidr() {
+        assert_eq!(
+            "192.168.0.0/24".parse::<Cidr>().unwrap(),
+            Cidr {
+                network: Ipv4Addr::new(192, 168, 0, 0).into(),
+                prefix: 24
+            }
+        );
+        assert!("0.0.0.0/0".parse::<Cidr>().is_ok());
+        assert!("::/0".parse::<Cidr>().is_ok());
+        assert!("2001:db8::1/128".parse::<Cidr>().is_ok());
+        assert!("192.168.0.0".parse::<Cidr>().is_err());
+        assert!("192.168.0.0/33".parse::<Cidr>().is_err());
+        assert!("2001:db8::/129".parse::<Cidr>().is_err());
+        assert!("192.168.0.1/24".parse::<Cidr>().is_err());
+        assert!("example.com/24".parse::<Cidr>().is_err());
+    }
+
+    #[test]
+    fn offsets() {
+        let cidr = "192.168.0.0/24".parse::<Cidr>().unwrap();
+        assert_eq!(cidr.offsets(false), IntRange::Unsigned { min: 0, max: 255 });
+        assert_eq!(cidr.offsets(true), IntRange::Unsigned { min: 1, max: 254 });
+        let cidr = "192.168.0.0/31".parse::<Cidr>().unwrap();
+        assert_eq!(cidr.offsets(true), IntRange::Unsigned { min: 0, max: 1 });
+        let cidr = "2001:db8::/64".parse::<Cidr>().unwrap();
+        assert_eq!(
+            cidr.offsets(true),
+            IntRange::Unsigned {
+                min: 1,
+                max: u128::from(u64::MAX)
+            }
+        );
+        let cidr = "::/0".parse::<Cidr>().unwrap();
+        assert_eq!(
+            cidr.offsets(false),
+            IntRange::Unsigned {
+                min: 0,
+                max: u128::MAX
+            }
+        );
+    }
+
+    #[test]
+    fn nth() {
+        let cidr = "10.0.0.0/8".parse::<Cidr>().unwrap();
+        assert_eq!(cidr.nth(0x0001_0203).to_string(), "10.1.2.3");
+        let cidr = "2001:db8::/32".parse::<Cidr>().unwrap();
+        assert_eq!(cidr.nth(1).to_string(), "2001:db8::1");
+        let cidr = "0.0.0.0/0".parse::<Cidr>().unwrap();
+        assert_eq!(
+            cidr.nth(u128::from(u32::MAX)).to_string(),
+            "255.255.255.255"
+        );
+    }
+
+    #[test]
+    fn parse_port_range() {
+        assert_eq!(
+            super::parse_port_range("0..65535").unwrap(),
+            IntRange::Unsigned { min: 0, max: 65535 }
+        );
+        assert_eq!(
+            super::parse_port_range("8000..=8080").unwrap(),
+            IntRange::Unsigned {
+                min: 8000,
+                max: 8080
+            }
+        );
+        assert!(super::parse_port_range("-1..80").is_err());
+        assert!(super::parse_port_range("80..65536").is_err());
+        assert!(super::parse_port_range("80").is_err());
+    }
+}
@@ -0,0 +1,203 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::collections::HashSet;
+
+use anyhow::ensure;
+
+use crate::{
+    cli::Case,
+    password::{DIGIT, SYMBOL},
+    rng::Rng,
+};
+
+/// The EFF large wordlist, which contains 7776 words.
+pub const EFF_LARGE_WORDLIST: &str = include_str!("assets/eff_large_wordlist.txt");
+
+/// The EFF short wordlist, which contains 1296 words.
+pub const EFF_SHORT_WORDLIST: &str = include_str!("assets/eff_short_wordlist.txt");
+
+/// Parses a wordlist.
+///
+/// Each non-empty line is a word. If a line consists of multiple fields
+/// separated by whitespace, such as a line of a diceware wordlist, the last
+/// field is the word. Each word is converted to `case`, and then duplicate
+/// words are removed.
+pub fn parse_wordlist(s: &str, case: Case) -> anyhow::Result<Vec<String>> {
+    let mut seen = HashSet::new();
+    let words = s
+        .lines()
+        .filter_map(|line| line.split_whitespace().last())
+        .map(|word| convert_case(word, case))
+        .filter(|word| seen.insert(word.clone()))
+        .collect::<Vec<_>>();
+    ensure!(
+        words.len() >= 2,
+        "wordlist should contain at least 2 distinct words"
+    );
+    Ok(words)
+}
+
+/// A generator of passphrases.
+#[derive(Debug)]
+pub struct Generator {
+    words: Vec<String>,
+    count: usize,
+    separator: String,
+    digit: bool,
+    symbol: bool,
+}
+
+impl Generator {
+    /// Creates a new `Generator` which picks `count` words from `words`.
+    pub const fn new(words: Vec<String>, count: usize, separator: String) -> Self {
+        Self {
+            words,
+            count,
+            separator,
+            digit: false,
+            symbol: false,
+        }
+    }
+
+    /// Returns the number of words needed to reach `bits` of entropy with
+    /// `len` words.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    pub fn words_for_entropy(bits: u32, len: usize) -> usize {
+        (f64::from(bits) / (len as f64).log2()).ceil().max(1.0) as usize
+    }
+
+    /// Appends a random digit to a random word if `digit` is `true`.
+    pub const fn digit(mut self, digit: bool) -> Self {
+        self.digit = digit;
+        self
+    }
+
+    /// Appends a random ASCII punctuation character to a random word if
+    /// `symbol` is `true`.
+    pub const fn symbol(mut self, symbol: bool) -> Self {
+        self.symbol = symbol;
+        self
+    }
+
+    /// Returns the entropy of a passphrase in bits.
+    ///
+    /// This assumes that the words, the digit and the symbol can be told apart
+    /// in a passphrase.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn entropy(&self) -> f64 {
+        let count = self.count as f64;
+        let mut entropy = count * (self.words.len() as f64).log2();
+        if self.digit {
+            entropy += (DIGIT.len() as f64).log2() + count.log2();
+        }
+        if self.symbol {
+            entropy += (SYMBOL.len() as f64).log2() + count.log2();
+        }
+        entropy
+    }
+
+    /// Generates a passphrase.
+    pub fn generate(&self, rng: &mut Rng) -> String {
+        let mut words = (0..self.count)
+            .map(|_| self.words[pick(rng, self.words.len())].clone())
+            .collect::<Vec<_>>();
+        for (inject, chars) in [(self.digit, DIGIT), (self.symbol, SYMBOL)] {
+            if inject {
+                let c = char::from(chars.as_bytes()[pick(rng, chars.len())]);
+                words[pick(rng, self.count)].push(c);
+            }
+        }
+        words.join(&self.separator)
+    }
+}
+
+/// Converts `word` to `case`.
+fn convert_case(word: &str, case: Case) -> String {
+    match case {
+        Case::Lower => word.to_lowercase(),
+        Case::Upper => word.to_uppercase(),
+        Case::Title => {
+            let mut chars = word.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_uppercase().chain(chars).collect()
+            })
+        }
+    }
+}
+
+/// Returns a uniformly distributed random index less than `len`.
+fn pick(rng: &mut Rng, len: usize) -> usize {
+    let len = u64::try_from(len).expect("length should fit in `u64`");
+    usize::try_from(rng.below(len)).expect("index should fit in `usize`")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_wordlists() {
+        assert_eq!(
+            parse_wordlist(EFF_LARGE_WORDLIST, Case::Lower)
+                .unwrap()
+                .len(),
+            7776
+        );
+        assert_eq!(
+            parse_wordlist(EFF_SHORT_WORDLIST, Case::Lower)
+                .unwrap()
+                .len(),
+            1296
+        );
+    }
+
+    #[test]
+    fn parse_diceware_wordlist() {
+        assert_eq!(
+            parse_wordlist(
+                "11111\tabacus\n11112\tabdomen\n\n11113 abacus\n",
+                Case::Lower
+            )
+            .unwrap(),
+            ["abacus", "abdomen"]
+        );
+        assert!(parse_wordlist("abacus\nabacus\n", Case::Lower).is_err());
+    }
+
+    #[test]
+    fn parse_wordlist_with_case() {
+        assert_eq!(
+            parse_wordlist("Abacus\nabacus\nABDOMEN\n", Case::Lower).unwrap(),
+            ["abacus", "abdomen"]
+        );
+        assert_eq!(
+            parse_wordlist("abacus\nAbacus\nabdomen\n", Case::Title).unwrap(),
+            ["Abacus", "Abdomen"]
+        );
+        assert_eq!(
+            parse_wordlist("abacus\nAbacus\nabdomen\n", Case::Upper).unwrap(),
+            ["ABACUS", "ABDOMEN"]
+        );
+        assert!(parse_wordlist("abacus\nABACUS\n", Case::Lower).is_err());
+    }
+
+    #[test]
+    fn words_for_entropy() {
+        assert_eq!(Generator::words_for_entropy(77, 7776), 6);
+        assert_eq!(Generator::words_for_entropy(78, 7776), 7);
+        assert_eq!(Generator::words_for_entropy(0, 7776), 1);
+    }
+
+    #[test]
+    fn entropy() {
+        let words = parse_wordlist(EFF_LARGE_WORDLIST, Case::Lower).unwrap();
+        let generator = Generator::new(words, 6, String::from(" "));
+        assert!((generator.entropy() - 77.548_875_021_634_69).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,244 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::f64::consts::LN_2;
+
+use anyhow::{Context, bail, ensure};
+
+use crate::rng::Rng;
+
+/// Lowercase letters.
+pub const LOWER: &str = "abcdefghijklmnopqrstuvwxyz";
+
+/// Uppercase letters.
+pub const UPPER: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Digits.
+pub const DIGIT: &str = "0123456789";
+
+/// ASCII punctuation characters.
+pub const SYMBOL: &str = "!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~";
+
+/// Characters which are easily confused with each other.
+pub const AMBIGUOUS: &str = "0O1Il|";
+
+// The minimum probability that a candidate satisfies the policy.
+//
+// Each password is drawn by rejection sampling, so a lower probability makes
+// the generation impractically slow.
+const MIN_ACCEPTANCE: f64 = 1.0 / (1 << 20) as f64;
+
+/// A set of characters and the minimum number of them in a password.
+#[derive(Debug)]
+pub struct CharClass {
+    pub name: &'static str,
+    pub chars: Vec<char>,
+    pub min: usize,
+}
+
+/// A policy which composes passwords.
+#[derive(Debug)]
+pub struct Policy {
+    alphabet: Vec<char>,
+    // The end of the range of `alphabet` for each character class.
+    bounds: Vec<usize>,
+    mins: Vec<usize>,
+    length: usize,
+    entropy: f64,
+}
+
+impl Policy {
+    /// Creates a new `Policy`.
+    ///
+    /// The character classes must be disjoint.
+    pub fn new(classes: Vec<CharClass>, length: usize) -> anyhow::Result<Self> {
+        let classes = classes
+            .into_iter()
+            .filter(|c| !c.chars.is_empty() || c.min > 0)
+            .collect::<Vec<_>>();
+        for class in &classes {
+            ensure!(
+                !class.chars.is_empty(),
+                "the {} characters are required, but all of them are excluded",
+                class.name
+            );
+        }
+        ensure!(!classes.is_empty(), "no characters are available");
+        let required = classes.iter().map(|c| c.min).sum::<usize>();
+        ensure!(
+            required <= length,
+            "the password length {length} is less than the number of required characters \
+             {required}"
+        );
+
+        let ln_count = ln_count(&classes, length);
+        let alphabet = classes
+            .iter()
+            .flat_map(|c| c.chars.iter().copied())
+            .collect::<Vec<_>>();
+        #[allow(clippy::cast_precision_loss)]
+        let ln_acceptance = (alphabet.len() as f64)
+            .ln()
+            .mul_add(-(length as f64), ln_count);
+        if ln_acceptance.exp() < MIN_ACCEPTANCE {
+            bail!("the password policy is too restrictive");
+        }
+        let bounds = classes
+            .iter()
+            .scan(0, |end, c| {
+                *end += c.chars.len();
+                Some(*end)
+            })
+            .collect();
+        Ok(Self {
+            alphabet,
+            bounds,
+            mins: classes.iter().map(|c| c.min).collect(),
+            length,
+            entropy: ln_count / LN_2,
+        })
+    }
+
+    /// Returns the entropy of a password in bits.
+    ///
+    /// Passwords are uniformly distributed over all strings which satisfy the
+    /// policy, so this is the base 2 logarithm of the number of them.
+    pub const fn entropy(&self) -> f64 {
+        self.entropy
+    }
+
+    /// Generates a password.
+    ///
+    /// Each character is drawn from all available characters without bias, and
+    /// the candidates which do not satisfy the policy are rejected.
+    pub fn generate(&self, rng: &mut Rng) -> String {
+        let len = u64::try_from(self.alphabet.len()).expect("alphabet should be small");
+        let mut indices = vec![usize::MIN; self.length];
+        let mut counts = vec![usize::MIN; self.bounds.len()];
+        loop {
+            counts.fill(usize::MIN);
+            for index in &mut indices {
+                *index = usize::try_from(rng.below(len)).expect("index should fit in `usize`");
+                counts[self.bounds.partition_point(|&end| end <= *index)] += 1;
+            }
+            if counts
+                .iter()
+                .zip(&self.mins)
+                .all(|(count, min)| count >= min)
+            {
+                return indices.iter().map(|&i| self.alphabet[i]).collect();
+            }
+        }
+    }
+}
+
+/// Returns the natural logarithm of the number of strings of `length`
+/// characters which satisfy the minimum counts of `classes`.
+#[allow(clippy::cast_precision_loss)]
+fn ln_count(classes: &[CharClass], length: usize) -> f64 {
+    // ln(n!) for 0..=length.
+    let mut ln_factorial = vec![0.0; length + 1];
+    for n in 1..=length {
+        ln_factorial[n] = ln_factorial[n - 1] + (n as f64).ln();
+    }
+    let ln_binomial = |n: usize, k: usize| ln_factorial[n] - ln_factorial[k] - ln_factorial[n - k];
+
+    // `dp[j]` is the logarithm of the number of ways to fill `j` positions with
+    // the classes processed so far.
+    let mut dp = vec![f64::NEG_INFINITY; length + 1];
+    dp[0] = 0.0;
+    for class in classes {
+        let ln_size = (class.chars.len() as f64).ln();
+        let mut next = vec![f64::NEG_INFINITY; length + 1];
+        for (used, &ln_ways) in dp.iter().enumerate() {
+            if ln_ways == f64::NEG_INFINITY {
+                continue;
+            }
+            for k in class.min..=(length - used) {
+                let term = ln_size.mul_add(k as f64, ln_ways + ln_binomial(length - used, k));
+                next[used + k] = ln_add_exp(next[used + k], term);
+            }
+        }
+        dp = next;
+    }
+    dp[length]
+}
+
+/// Returns `ln(exp(a) + exp(b))`.
+fn ln_add_exp(a: f64, b: f64) -> f64 {
+    let (max, min) = if a > b { (a, b) } else { (b, a) };
+    if min == f64::NEG_INFINITY {
+        return max;
+    }
+    max + (min - max).exp().ln_1p()
+}
+
+/// Returns the characters of `s` which are not ambiguous if `exclude_ambiguous`
+/// is `true`.
+pub fn chars(s: &str, exclude_ambiguous: bool) -> Vec<char> {
+    s.chars()
+        .filter(|&c| !exclude_ambiguous || !AMBIGUOUS.contains(c))
+        .collect()
+}
+
+/// Parses a custom character set.
+///
+/// Returns the characters in sorted order without duplicates.
+pub fn parse_custom(s: &str) -> anyhow::Result<String> {
+    let mut chars = s.chars().collect::<Vec<_>>();
+    chars.sort_unstable();
+    chars.dedup();
+    if let Some(c) = chars.iter().find(|c| c.is_control()) {
+        bail!("custom characters contain a control character {c:?}");
+    }
+    chars
+        .first()
+        .context("custom characters should not be empty")?;
+    Ok(chars.into_iter().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::test_rng;
+
+    fn class(chars: &str, min: usize) -> CharClass {
+        CharClass {
+            name: "test",
+            chars: chars.chars().collect(),
+            min,
+        }
+    }
+
+    #[test]
+    fn entropy_without_requirement() {
+        let policy = Policy::new(vec![class(LOWER, 0), class(DIGIT, 0)], 8).unwrap();
+        let expected = 8.0 * 36_f64.log2();
+        assert!((policy.entropy() - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn entropy_with_requirement() {
+        // All strings of length 2 over "ab" and "0" which contain both
+        // classes: "a0", "0a", "b0" and "0b".
+        let policy = Policy::new(vec![class("ab", 1), class("0", 1)], 2).unwrap();
+        assert!((policy.entropy() - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn too_restrictive() {
+        assert!(Policy::new(vec![class(LOWER, 0), class("!", 8)], 8).is_err());
+        assert!(Policy::new(vec![class(LOWER, 4), class(DIGIT, 5)], 8).is_err());
+    }
+
+    #[test]
+    fn generate() {
+        let policy = Policy::new(vec![class("ab", 1), class("0", 1)], 2).unwrap();
+        let mut rng = test_rng();
+        for _ in 0..100 {
+            let password = policy.generate(&mut rng);
+            assert!(["a0", "0a", "b0", "0b"].contains(&password.as_str()));
+        }
+    }
+}
@@ -0,0 +1,178 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use rand_chacha::ChaCha8Rng;
+use rand_core::{RngCore, SeedableRng};
+
+use crate::rng::Rng;
+
+/// The largest number of elements which are permuted in memory.
+pub const IN_MEMORY_MAX: u128 = 1 << 20;
+
+/// A keyed pseudorandom permutation of the integers from 0 to `max`.
+///
+/// This is a balanced Feistel network whose round function is `ChaCha8` keyed
+/// with random bytes. Values which fall outside the domain are encrypted again
+/// until they fall inside it (cycle walking).
+#[derive(Debug)]
+pub struct Permutation {
+    key: [u8; 32],
+    max: u128,
+    half_bits: u32,
+}
+
+impl Permutation {
+    // The number of rounds of the Feistel network.
+    const ROUNDS: u32 = 8;
+
+    /// Creates a new `Permutation` of the integers from 0 to `max` with a key
+    /// drawn from `rng`.
+    pub fn new(rng: &mut Rng, max: u128) -> Self {
+        let mut key = [u8::MIN; 32];
+        rng.fill_bytes(&mut key);
+        let bits = (u128::BITS - max.leading_zeros()).max(2);
+        Self {
+            key,
+            max,
+            half_bits: bits.div_ceil(2),
+        }
+    }
+
+    /// Returns the image of `n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than `max`.
+    pub fn apply(&self, n: u128) -> u128 {
+        assert!(n <= self.max, "`n` should be in the domain");
+        // Since the Feistel network permutes less than 4 times as many values
+        // as the domain, this terminates after a few iterations on average.
+        let mut n = self.feistel(n);
+        while n > self.max {
+            n = self.feistel(n);
+        }
+        n
+    }
+
+    fn feistel(&self, n: u128) -> u128 {
+        let mask = u64::MAX >> (u64::BITS - self.half_bits);
+        let (mut left, mut right) = (
+            u64::try_from(n >> self.half_bits).expect("left half should fit in `u64`"),
+            u64::try_from(n & u128::from(mask)).expect("right half should fit in `u64`"),
+        );
+        for round in 0..Self::ROUNDS {
+            (left, right) = (right, left ^ (self.round(round, right) & mask));
+        }
+        (u128::from(left) << self.half_bits) | u128::from(right)
+    }
+
+    fn round(&self, round: u32, half: u64) -> u64 {
+        let mut rng = ChaCha8Rng::from_seed(self.key);
+        rng.set_stream(half);
+        // Each round uses a different block of the keystream.
+        rng.set_word_pos(u128::from(round) * 16);
+        rng.next_u64()
+    }
+}
+
+/// A random permutation of the integers from 0 to `len` (exclusive).
+///
+/// If `len` is at most [`IN_MEMORY_MAX`], the permutation is generated in memory
+/// by the Fisher-Yates shuffle. Otherwise, each element is computed from its
+/// index by [`Permutation`], so the permutation is never stored.
+#[derive(Debug)]
+pub enum RangePermutation {
+    /// The shuffled elements.
+    Table(Vec<u64>),
+
+    /// The keyed permutation of the domain.
+    Cipher(Permutation),
+}
+
+impl RangePermutation {
+    /// Creates a new `RangePermutation` of `len` elements.
+    pub fn new(rng: &mut Rng, len: u128) -> Self {
+        if len > IN_MEMORY_MAX {
+            return Self::Cipher(Permutation::new(rng, len - 1));
+        }
+        let len = u64::try_from(len).expect("length should fit in `u64`");
+        let mut table = (0..len).collect::<Vec<_>>();
+        for i in (1..len).rev() {
+            let j = rng.below(i + 1);
+            table.swap(
+                usize::try_from(i).expect("index should fit in `usize`"),
+                usize::try_from(j).expect("index should fit in `usize`"),
+            );
+        }
+        Self::Table(table)
+    }
+
+    /// Returns the element at `index`, or [`None`] if `index` is out of range.
+    pub fn get(&self, index: u128) -> Option<u128> {
+        match self {
+            Self::Table(table) => usize::try_from(index)
+                .ok()
+                .and_then(|index| table.get(index))
+                .map(|&n| u128::from(n)),
+            Self::Cipher(permutation) => {
+                (index <= permutation.max).then(|| permutation.apply(index))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::rng::test_rng;
+
+    #[test]
+    fn is_bijective() {
+        for max in [0, 1, 2, 6, 255, 1000] {
+            let permutation = Permutation::new(&mut test_rng(), max);
+            let image = (0..=max)
+                .map(|n| permutation.apply(n))
+                .collect::<HashSet<_>>();
+            assert_eq!(image.len(), usize::try_from(max + 1).unwrap());
+            assert!(image.iter().all(|&n| n <= max));
+        }
+    }
+
+    #[test]
+    fn full_domain() {
+        let permutation = Permutation::new(&mut test_rng(), u128::MAX);
+        let image = (0..1000)
+            .map(|n| permutation.apply(n))
+            .collect::<HashSet<_>>();
+        assert_eq!(image.len(), 1000);
+    }
+
+    #[test]
+    fn range_permutation() {
+        for len in [0, 1, 10, IN_MEMORY_MAX, IN_MEMORY_MAX + 1] {
+            let permutation = RangePermutation::new(&mut test_rng(), len);
+            assert_eq!(
+                matches!(permutation, RangePermutation::Cipher(_)),
+                len > IN_MEMORY_MAX
+            );
+            let count = len.min(1000);
+            let image = (0..count)
+                .map(|i| permutation.get(i).unwrap())
+                .collect::<HashSet<_>>();
+            assert_eq!(image.len(), usize::try_from(count).unwrap());
+            assert!(image.iter().all(|&n| n < len));
+            assert!(permutation.get(len).is_none());
+        }
+    }
+
+    #[test]
+    fn table_is_shuffled() {
+        let RangePermutation::Table(table) = RangePermutation::new(&mut test_rng(), 100) else {
+            unreachable!();
+        };
+        assert_ne!(table, (0..100).collect::<Vec<_>>());
+    }
+}
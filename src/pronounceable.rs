@@ -0,0 +1,715 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+/// The consonants of a proquint, each of which represents 4 bits.
+const CONSONANTS: &[u8; 16] = b"bdfghjklmnprstvz";
+
+/// The vowels of a proquint, each of which represents 2 bits.
+const VOWELS: &[u8; 4] = b"aiou";
+
+/// An encoder which encodes bytes as proquints.
+///
+/// Each quint of five letters represents 16 bits, and the quints are separated
+/// by `-`. If the number of bytes is odd, the last quint is padded with a zero
+/// byte.
+#[derive(Debug)]
+pub struct Proquint {
+    pending: Option<u8>,
+    is_first: bool,
+}
+
+impl Proquint {
+    /// Creates a new `Proquint`.
+    pub const fn new() -> Self {
+        Self {
+            pending: None,
+            is_first: true,
+        }
+    }
+
+    /// Returns the number of bytes of the result of encoding `len` bytes.
+    ///
+    /// Returns [`None`] if the length overflows.
+    pub fn encoded_len(len: usize) -> Option<usize> {
+        let quints = len.div_ceil(2);
+        Some(quints.checked_mul(6)?.saturating_sub(1))
+    }
+
+    /// Encodes `bytes`.
+    ///
+    /// The byte which does not fill a quint is kept until the next call or
+    /// [`Proquint::finish`].
+    pub fn encode(&mut self, bytes: &[u8]) -> String {
+        let mut s = String::with_capacity(bytes.len() * 3 + 3);
+        for &byte in bytes {
+            if let Some(high) = self.pending.take() {
+                self.push_quint(&mut s, u16::from_be_bytes([high, byte]));
+            } else {
+                self.pending = Some(byte);
+            }
+        }
+        s
+    }
+
+    /// Encodes the remaining byte.
+    pub fn finish(&mut self) -> String {
+        let mut s = String::new();
+        if let Some(high) = self.pending.take() {
+            self.push_quint(&mut s, u16::from_be_bytes([high, u8::MIN]));
+        }
+        self.is_first = true;
+        s
+    }
+
+    fn push_quint(&mut self, s: &mut String, value: u16) {
+        if !self.is_first {
+            s.push('-');
+        }
+        let consonant = |shift: u16| char::from(CONSONANTS[usize::from((value >> shift) & 0xf)]);
+        let vowel = |shift: u16| char::from(VOWELS[usize::from((value >> shift) & 0b11)]);
+        s.extend([
+            consonant(12),
+            vowel(10),
+            consonant(6),
+            vowel(4),
+            consonant(0),
+        ]);
+        self.is_first = false;
+    }
+}
+
+/// An encoder which encodes bytes as words of the PGP word list.
+///
+/// The bytes at even positions are encoded as the words which have two
+/// syllables, and the bytes at odd positions are encoded as the words which
+/// have three syllables. The words are separated by a space.
+#[derive(Debug)]
+pub struct PgpWords {
+    is_odd: bool,
+    is_first: bool,
+}
+
+impl PgpWords {
+    /// Creates a new `PgpWords`.
+    pub const fn new() -> Self {
+        Self {
+            is_odd: false,
+            is_first: true,
+        }
+    }
+
+    /// Returns the maximum number of bytes of the result of encoding `len`
+    /// bytes.
+    ///
+    /// Since the words have different lengths, the actual length depends on
+    /// the bytes. Returns [`None`] if the length overflows.
+    pub fn max_encoded_len(len: usize) -> Option<usize> {
+        let max_len = |words: &[&str]| {
+            words
+                .iter()
+                .map(|word| word.len())
+                .max()
+                .unwrap_or_default()
+        };
+        let (even, odd) = (len.div_ceil(2), len / 2);
+        even.checked_mul(max_len(&EVEN_WORDS))?
+            .checked_add(odd.checked_mul(max_len(&ODD_WORDS))?)?
+            .checked_add(len.saturating_sub(1))
+    }
+
+    /// Encodes `bytes`.
+    pub fn encode(&mut self, bytes: &[u8]) -> String {
+        let mut s = String::with_capacity(bytes.len() * 12);
+        for &byte in bytes {
+            if !self.is_first {
+                s.push(' ');
+            }
+            let words = if self.is_odd { &ODD_WORDS } else { &EVEN_WORDS };
+            s.push_str(words[usize::from(byte)]);
+            self.is_odd = !self.is_odd;
+            self.is_first = false;
+        }
+        s
+    }
+}
+
+/// The words for the bytes at even positions, which have two syllables.
+const EVEN_WORDS: [&str; 256] = [
+    "aardvark",
+    "absurd",
+    "accrue",
+    "acme",
+    "adrift",
+    "adult",
+    "afflict",
+    "ahead",
+    "aimless",
+    "algol",
+    "allow",
+    "alone",
+    "ammo",
+    "ancient",
+    "apple",
+    "artist",
+    "assume",
+    "athens",
+    "atlas",
+    "aztec",
+    "baboon",
+    "backfield",
+    "backward",
+    "banjo",
+    "beaming",
+    "bedlamp",
+    "beehive",
+    "beeswax",
+    "befriend",
+    "belfast",
+    "berserk",
+    "billiard",
+    "bison",
+    "blackjack",
+    "blockade",
+    "blowtorch",
+    "bluebird",
+    "bombast",
+    "bookshelf",
+    "brackish",
+    "breadline",
+    "breakup",
+    "brickyard",
+    "briefcase",
+    "burbank",
+    "button",
+    "buzzard",
+    "cement",
+    "chairlift",
+    "chatter",
+    "checkup",
+    "chisel",
+    "choking",
+    "chopper",
+    "christmas",
+    "clamshell",
+    "classic",
+    "classroom",
+    "cleanup",
+    "clockwork",
+    "cobra",
+    "commence",
+    "concert",
+    "cowbell",
+    "crackdown",
+    "cranky",
+    "crowfoot",
+    "crucial",
+    "crumpled",
+    "crusade",
+    "cubic",
+    "dashboard",
+    "deadbolt",
+    "deckhand",
+    "dogsled",
+    "dragnet",
+    "drainage",
+    "dreadful",
+    "drifter",
+    "dropper",
+    "drumbeat",
+    "drunken",
+    "dupont",
+    "dwelling",
+    "eating",
+    "edict",
+    "egghead",
+    "eightball",
+    "endorse",
+    "endow",
+    "enlist",
+    "erase",
+    "escape",
+    "exceed",
+    "eyeglass",
+    "eyetooth",
+    "facial",
+    "fallout",
+    "flagpole",
+    "flatfoot",
+    "flytrap",
+    "fracture",
+    "framework",
+    "freedom",
+    "frighten",
+    "gazelle",
+    "geiger",
+    "glitter",
+    "glucose",
+    "goggles",
+    "goldfish",
+    "gremlin",
+    "guidance",
+    "hamlet",
+    "highchair",
+    "hockey",
+    "indoors",
+    "indulge",
+    "inverse",
+    "involve",
+    "island",
+    "jawbone",
+    "keyboard",
+    "kickoff",
+    "kiwi",
+    "klaxon",
+    "locale",
+    "lockup",
+    "merit",
+    "minnow",
+    "miser",
+    "mohawk",
+    "mural",
+    "music",
+    "necklace",
+    "neptune",
+    "newborn",
+    "nightbird",
+    "oakland",
+    "obtuse",
+    "offload",
+    "optic",
+    "orca",
+    "payday",
+    "peachy",
+    "pheasant",
+    "physique",
+    "playhouse",
+    "pluto",
+    "preclude",
+    "prefer",
+    "preshrunk",
+    "printer",
+    "prowler",
+    "pupil",
+    "puppy",
+    "python",
+    "quadrant",
+    "quiver",
+    "quota",
+    "ragtime",
+    "ratchet",
+    "rebirth",
+    "reform",
+    "regain",
+    "reindeer",
+    "rematch",
+    "repay",
+    "retouch",
+    "revenge",
+    "reward",
+    "rhythm",
+    "ribcage",
+    "ringbolt",
+    "robust",
+    "rocker",
+    "ruffled",
+    "sailboat",
+    "sawdust",
+    "scallion",
+    "scenic",
+    "scorecard",
+    "scotland",
+    "seabird",
+    "select",
+    "sentence",
+    "shadow",
+    "shamrock",
+    "showgirl",
+    "skullcap",
+    "skydive",
+    "slingshot",
+    "slowdown",
+    "snapline",
+    "snapshot",
+    "snowcap",
+    "snowslide",
+    "solo",
+    "southward",
+    "soybean",
+    "spaniel",
+    "spearhead",
+    "spellbind",
+    "spheroid",
+    "spigot",
+    "spindle",
+    "spyglass",
+    "stagehand",
+    "stagnate",
+    "stairway",
+    "standard",
+    "stapler",
+    "steamship",
+    "sterling",
+    "stockman",
+    "stopwatch",
+    "stormy",
+    "sugar",
+    "surmount",
+    "suspense",
+    "sweatband",
+    "swelter",
+    "tactics",
+    "talon",
+    "tapeworm",
+    "tempest",
+    "tiger",
+    "tissue",
+    "tonic",
+    "topmost",
+    "tracker",
+    "transit",
+    "trauma",
+    "treadmill",
+    "trojan",
+    "trouble",
+    "tumor",
+    "tunnel",
+    "tycoon",
+    "uncut",
+    "unearth",
+    "unwind",
+    "uproot",
+    "upset",
+    "upshot",
+    "vapor",
+    "village",
+    "virus",
+    "vulcan",
+    "waffle",
+    "wallet",
+    "watchword",
+    "wayside",
+    "willow",
+    "woodlark",
+    "zulu",
+];
+
+/// The words for the bytes at odd positions, which have three syllables.
+const ODD_WORDS: [&str; 256] = [
+    "adroitness",
+    "adviser",
+    "aftermath",
+    "aggregate",
+    "alkali",
+    "almighty",
+    "amulet",
+    "amusement",
+    "antenna",
+    "applicant",
+    "apollo",
+    "armistice",
+    "article",
+    "asteroid",
+    "atlantic",
+    "atmosphere",
+    "autopsy",
+    "babylon",
+    "backwater",
+    "barbecue",
+    "belowground",
+    "bifocals",
+    "bodyguard",
+    "bookseller",
+    "borderline",
+    "bottomless",
+    "bradbury",
+    "bravado",
+    "brazilian",
+    "breakaway",
+    "burlington",
+    "businessman",
+    "butterfat",
+    "camelot",
+    "candidate",
+    "cannonball",
+    "capricorn",
+    "caravan",
+    "caretaker",
+    "celebrate",
+    "cellulose",
+    "certify",
+    "chambermaid",
+    "cherokee",
+    "chicago",
+    "clergyman",
+    "coherence",
+    "combustion",
+    "commando",
+    "company",
+    "component",
+    "concurrent",
+    "confidence",
+    "conformist",
+    "congregate",
+    "consensus",
+    "consulting",
+    "corporate",
+    "corrosion",
+    "councilman",
+    "crossover",
+    "crucifix",
+    "cumbersome",
+    "customer",
+    "dakota",
+    "decadence",
+    "december",
+    "decimal",
+    "designing",
+    "detector",
+    "detergent",
+    "determine",
+    "dictator",
+    "dinosaur",
+    "direction",
+    "disable",
+    "disbelief",
+    "disruptive",
+    "distortion",
+    "document",
+    "embezzle",
+    "enchanting",
+    "enrollment",
+    "enterprise",
+    "equation",
+    "equipment",
+    "escapade",
+    "eskimo",
+    "everyday",
+    "examine",
+    "existence",
+    "exodus",
+    "fascinate",
+    "filament",
+    "finicky",
+    "forever",
+    "fortitude",
+    "frequency",
+    "gadgetry",
+    "galveston",
+    "getaway",
+    "glossary",
+    "gossamer",
+    "graduate",
+    "gravity",
+    "guitarist",
+    "hamburger",
+    "hamilton",
+    "handiwork",
+    "hazardous",
+    "headwaters",
+    "hemisphere",
+    "hesitate",
+    "hideaway",
+    "holiness",
+    "hurricane",
+    "hydraulic",
+    "impartial",
+    "impetus",
+    "inception",
+    "indigo",
+    "inertia",
+    "infancy",
+    "inferno",
+    "informant",
+    "insincere",
+    "insurgent",
+    "integrate",
+    "intention",
+    "inventive",
+    "istanbul",
+    "jamaica",
+    "jupiter",
+    "leprosy",
+    "letterhead",
+    "liberty",
+    "maritime",
+    "matchmaker",
+    "maverick",
+    "medusa",
+    "megaton",
+    "microscope",
+    "microwave",
+    "midsummer",
+    "millionaire",
+    "miracle",
+    "misnomer",
+    "molasses",
+    "molecule",
+    "montana",
+    "monument",
+    "mosquito",
+    "narrative",
+    "nebula",
+    "newsletter",
+    "norwegian",
+    "october",
+    "ohio",
+    "onlooker",
+    "opulent",
+    "orlando",
+    "outfielder",
+    "pacific",
+    "pandemic",
+    "pandora",
+    "paperweight",
+    "paragon",
+    "paragraph",
+    "paramount",
+    "passenger",
+    "pedigree",
+    "pegasus",
+    "penetrate",
+    "perceptive",
+    "performance",
+    "pharmacy",
+    "phonetic",
+    "photograph",
+    "pioneer",
+    "pocketful",
+    "politeness",
+    "positive",
+    "potato",
+    "processor",
+    "provincial",
+    "proximate",
+    "puberty",
+    "publisher",
+    "pyramid",
+    "quantity",
+    "racketeer",
+    "rebellion",
+    "recipe",
+    "recover",
+    "repellent",
+    "replica",
+    "reproduce",
+    "resistor",
+    "responsive",
+    "retraction",
+    "retrieval",
+    "retrospect",
+    "revenue",
+    "revival",
+    "revolver",
+    "sandalwood",
+    "sardonic",
+    "saturday",
+    "savagery",
+    "scavenger",
+    "sensation",
+    "sociable",
+    "souvenir",
+    "specialist",
+    "speculate",
+    "stethoscope",
+    "stupendous",
+    "supportive",
+    "surrender",
+    "suspicious",
+    "sympathy",
+    "tambourine",
+    "telephone",
+    "therapist",
+    "tobacco",
+    "tolerance",
+    "tomorrow",
+    "torpedo",
+    "tradition",
+    "travesty",
+    "trombonist",
+    "truncated",
+    "typewriter",
+    "ultimate",
+    "undaunted",
+    "underfoot",
+    "unicorn",
+    "unify",
+    "universe",
+    "unravel",
+    "upcoming",
+    "vacancy",
+    "vagabond",
+    "vertigo",
+    "virginia",
+    "visitor",
+    "vocalist",
+    "voyager",
+    "warranty",
+    "waterloo",
+    "whimsical",
+    "wichita",
+    "wilmington",
+    "wyoming",
+    "yesteryear",
+    "yucatan",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn proquint() {
+        let mut proquint = Proquint::new();
+        assert_eq!(proquint.encode(&[0x7f, 0x00, 0x00]), "lusab");
+        assert_eq!(proquint.encode(&[0x01]), "-babad");
+        assert_eq!(proquint.finish(), "");
+        assert_eq!(proquint.encode(&[0x3f, 0x54, 0xdc, 0xc1]), "gutih-tugad");
+        assert_eq!(proquint.encode(&[0xff]), "");
+        assert_eq!(proquint.finish(), "-zusab");
+    }
+
+    #[test]
+    fn proquint_encoded_len() {
+        let bytes = [0x00, 0x5a, 0xff, 0x81, 0x7e];
+        for len in 0..=bytes.len() {
+            let mut proquint = Proquint::new();
+            let encoded = proquint.encode(&bytes[..len]) + &proquint.finish();
+            assert_eq!(Proquint::encoded_len(len), Some(encoded.len()));
+        }
+    }
+
+    #[test]
+    fn pgp_words() {
+        let mut pgp_words = PgpWords::new();
+        assert_eq!(
+            pgp_words.encode(&[0x2d, 0x6d, 0xed]),
+            "button hazardous tunnel"
+        );
+        assert_eq!(pgp_words.encode(&[0x27]), " celebrate");
+        assert_eq!(
+            PgpWords::new().encode(&[0x00, 0x00, 0xff, 0xff]),
+            "aardvark adroitness zulu yucatan"
+        );
+    }
+
+    #[test]
+    fn pgp_words_max_encoded_len() {
+        assert_eq!(PgpWords::max_encoded_len(0), Some(0));
+        assert_eq!(PgpWords::max_encoded_len(1), Some(9));
+        assert_eq!(PgpWords::max_encoded_len(2), Some(21));
+        let bytes = (u8::MIN..=u8::MAX).collect::<Vec<_>>();
+        let encoded = PgpWords::new().encode(&bytes);
+        assert!(encoded.len() <= PgpWords::max_encoded_len(bytes.len()).unwrap());
+    }
+
+    #[test]
+    fn pgp_word_lists() {
+        for words in [EVEN_WORDS, ODD_WORDS] {
+            let mut sorted = words.to_vec();
+            sorted.sort_unstable();
+            sorted.dedup();
+            assert_eq!(sorted.len(), 256);
+        }
+    }
+}
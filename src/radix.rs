@@ -0,0 +1,167 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::cli::{BitGroup, BitOrder};
+
+/// An encoder which encodes bytes as a string of `0` and `1`.
+#[derive(Debug)]
+pub struct Binary {
+    order: BitOrder,
+    group: BitGroup,
+    is_first: bool,
+}
+
+impl Binary {
+    /// Creates a new `Binary`.
+    pub const fn new(order: BitOrder, group: BitGroup) -> Self {
+        Self {
+            order,
+            group,
+            is_first: true,
+        }
+    }
+
+    /// Returns the number of bytes of the result of encoding `len` bytes.
+    ///
+    /// Returns [`None`] if the length overflows.
+    pub fn encoded_len(len: usize, group: BitGroup) -> Option<usize> {
+        let separators = match group {
+            BitGroup::None => 0,
+            BitGroup::Nibble => len.checked_mul(2)?.saturating_sub(1),
+            BitGroup::Byte => len.saturating_sub(1),
+        };
+        len.checked_mul(8)?.checked_add(separators)
+    }
+
+    /// Encodes `bytes`.
+    pub fn encode(&mut self, bytes: &[u8]) -> String {
+        let mut s = String::with_capacity(bytes.len() * 10);
+        for &byte in bytes {
+            let byte = match self.order {
+                BitOrder::Msb => byte,
+                BitOrder::Lsb => byte.reverse_bits(),
+            };
+            let digits = format!("{byte:08b}");
+            if !self.is_first && self.group != BitGroup::None {
+                s.push(' ');
+            }
+            if self.group == BitGroup::Nibble {
+                s.push_str(&digits[..4]);
+                s.push(' ');
+                s.push_str(&digits[4..]);
+            } else {
+                s.push_str(&digits);
+            }
+            self.is_first = false;
+        }
+        s
+    }
+}
+
+/// An encoder which encodes bytes as octal digits.
+///
+/// The bytes are regarded as a bit string, and each octal digit represents 3
+/// bits of it. If the number of bits is not a multiple of 3, the last digit is
+/// padded with zero bits.
+#[derive(Debug)]
+pub struct Octal {
+    order: BitOrder,
+    acc: u16,
+    bits: u32,
+}
+
+impl Octal {
+    /// Creates a new `Octal`.
+    pub const fn new(order: BitOrder) -> Self {
+        Self {
+            order,
+            acc: u16::MIN,
+            bits: u32::MIN,
+        }
+    }
+
+    /// Returns the number of bytes of the result of encoding `len` bytes.
+    ///
+    /// Returns [`None`] if the length overflows.
+    pub fn encoded_len(len: usize) -> Option<usize> {
+        Some(len.checked_mul(8)?.div_ceil(3))
+    }
+
+    /// Encodes `bytes`.
+    ///
+    /// The bits which do not fill an octal digit are kept until the next call
+    /// or [`Octal::finish`].
+    pub fn encode(&mut self, bytes: &[u8]) -> String {
+        let mut s = String::with_capacity(bytes.len() * 3);
+        for &byte in bytes {
+            let byte = match self.order {
+                BitOrder::Msb => byte,
+                BitOrder::Lsb => byte.reverse_bits(),
+            };
+            self.acc = (self.acc << 8) | u16::from(byte);
+            self.bits += 8;
+            while self.bits >= 3 {
+                self.bits -= 3;
+                s.push(Self::digit(self.acc >> self.bits));
+            }
+            self.acc &= (1 << self.bits) - 1;
+        }
+        s
+    }
+
+    /// Encodes the remaining bits.
+    pub fn finish(&mut self) -> String {
+        let s = if self.bits > 0 {
+            Self::digit(self.acc << (3 - self.bits)).into()
+        } else {
+            String::new()
+        };
+        (self.acc, self.bits) = (u16::MIN, u32::MIN);
+        s
+    }
+
+    fn digit(value: u16) -> char {
+        char::from(b'0' + u8::try_from(value & 0b111).expect("octal digit should be less than 8"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn binary_encoded_len() {
+        let bytes = [0x00, 0x5a, 0xff, 0x81];
+        for group in [BitGroup::None, BitGroup::Nibble, BitGroup::Byte] {
+            for len in 0..=bytes.len() {
+                let encoded = Binary::new(BitOrder::Msb, group).encode(&bytes[..len]);
+                assert_eq!(Binary::encoded_len(len, group), Some(encoded.len()));
+            }
+        }
+    }
+
+    #[test]
+    fn octal_encoded_len() {
+        let bytes = [0x00, 0x5a, 0xff, 0x81, 0x7e];
+        for len in 0..=bytes.len() {
+            let mut octal = Octal::new(BitOrder::Msb);
+            let encoded = octal.encode(&bytes[..len]) + &octal.finish();
+            assert_eq!(Octal::encoded_len(len), Some(encoded.len()));
+        }
+    }
+
+    #[test]
+    fn octal() {
+        let mut octal = Octal::new(BitOrder::Msb);
+        // 0b111_111_111_000_000_001_110_101
+        let encoded = octal.encode(&[0xff]) + &octal.encode(&[0x80, 0x75]) + &octal.finish();
+        assert_eq!(encoded, "77700165");
+        let mut octal = Octal::new(BitOrder::Msb);
+        // 0b000_000_01 + padding
+        assert_eq!(octal.encode(&[0x01]) + &octal.finish(), "002");
+        let mut octal = Octal::new(BitOrder::Lsb);
+        // 0b100_000_00 + padding
+        assert_eq!(octal.encode(&[0x01]) + &octal.finish(), "400");
+    }
+}
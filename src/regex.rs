@@ -0,0 +1,470 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::{iter::Peekable, str::Chars, str::FromStr};
+
+use anyhow::{Context, bail, ensure};
+
+use crate::rng::Rng;
+
+/// The default maximum number of repetitions of unbounded repetition.
+pub const MAX_REPEAT: u32 = 8;
+
+/// The characters which `.` and negated character classes match.
+const PRINTABLE: (char, char) = (' ', '~');
+
+/// A regular expression which generates matching strings.
+///
+/// This supports a subset of the usual syntax: literals, `.`, character
+/// classes (including `\d`, `\w` and `\s`), groups, alternation and
+/// repetition. `^` and `$` are accepted and ignored.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Regex(Node);
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Node {
+    Empty,
+    Literal(char),
+    Class(CharSet),
+    Concat(Vec<Self>),
+    Alternation(Vec<Self>),
+    Repeat {
+        node: Box<Self>,
+        min: u32,
+        max: Option<u32>,
+    },
+}
+
+impl Regex {
+    /// Generates a string which matches the regular expression.
+    ///
+    /// Each branch of an alternation, each character of a character class and
+    /// each number of repetitions is chosen uniformly. `max_repeat` is the
+    /// upper bound of unbounded repetition, such as `*` and `+`.
+    pub fn generate(&self, rng: &mut Rng, max_repeat: u32) -> String {
+        let mut s = String::new();
+        self.0.generate(rng, max_repeat, &mut s);
+        s
+    }
+}
+
+impl Node {
+    fn generate(&self, rng: &mut Rng, max_repeat: u32, s: &mut String) {
+        match self {
+            Self::Empty => {}
+            Self::Literal(c) => s.push(*c),
+            Self::Class(set) => s.push(set.sample(rng)),
+            Self::Concat(nodes) => nodes.iter().for_each(|n| n.generate(rng, max_repeat, s)),
+            Self::Alternation(nodes) => {
+                let len = u64::try_from(nodes.len()).expect("number of branches should be small");
+                let index = usize::try_from(rng.below(len)).expect("index should fit in `usize`");
+                nodes[index].generate(rng, max_repeat, s);
+            }
+            Self::Repeat { node, min, max } => {
+                let max = max.unwrap_or_else(|| max_repeat.max(*min));
+                let count = min
+                    + u32::try_from(rng.below(u64::from(max - min) + 1))
+                        .expect("number of repetitions should fit in `u32`");
+                (0..count).for_each(|_| node.generate(rng, max_repeat, s));
+            }
+        }
+    }
+}
+
+impl FromStr for Regex {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            chars: s.chars().peekable(),
+        };
+        let node = parser.alternation()?;
+        if let Some(c) = parser.chars.next() {
+            bail!("unexpected {c:?}");
+        }
+        Ok(Self(node))
+    }
+}
+
+struct Parser<'a> {
+    chars: Peekable<Chars<'a>>,
+}
+
+impl Parser<'_> {
+    fn alternation(&mut self) -> anyhow::Result<Node> {
+        let mut branches = vec![self.concat()?];
+        while self.chars.next_if_eq(&'|').is_some() {
+            branches.push(self.concat()?);
+        }
+        Ok(if branches.len() == 1 {
+            branches.remove(0)
+        } else {
+            Node::Alternation(branches)
+        })
+    }
+
+    fn concat(&mut self) -> anyhow::Result<Node> {
+        let mut nodes = Vec::new();
+        while let Some(&c) = self.chars.peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            let atom = self.atom()?;
+            nodes.push(self.repeat(atom)?);
+        }
+        Ok(Node::Concat(nodes))
+    }
+
+    fn repeat(&mut self, mut node: Node) -> anyhow::Result<Node> {
+        loop {
+            let (min, max) = match self.chars.peek() {
+                Some('*') => (0, None),
+                Some('+') => (1, None),
+                Some('?') => (0, Some(1)),
+                Some('{') => {
+                    self.chars.next();
+                    let min = self.number()?.context("repetition should have a minimum")?;
+                    let max = if self.chars.next_if_eq(&',').is_some() {
+                        self.number()?
+                    } else {
+                        Some(min)
+                    };
+                    ensure!(
+                        self.chars.next_if_eq(&'}').is_some(),
+                        "repetition should be closed by `}}`"
+                    );
+                    if max.is_some_and(|max| max < min) {
+                        bail!("maximum repetition should be greater than or equal to minimum");
+                    }
+                    node = Node::Repeat {
+                        node: Box::new(node),
+                        min,
+                        max,
+                    };
+                    self.chars.next_if_eq(&'?');
+                    continue;
+                }
+                _ => return Ok(node),
+            };
+            self.chars.next();
+            // A lazy quantifier generates the same strings.
+            self.chars.next_if_eq(&'?');
+            node = Node::Repeat {
+                node: Box::new(node),
+                min,
+                max,
+            };
+        }
+    }
+
+    fn number(&mut self) -> anyhow::Result<Option<u32>> {
+        let mut digits = String::new();
+        while let Some(c) = self.chars.next_if(char::is_ascii_digit) {
+            digits.push(c);
+        }
+        if digits.is_empty() {
+            return Ok(None);
+        }
+        let n = digits
+            .parse()
+            .with_context(|| format!("repetition `{digits}` is too large"))?;
+        Ok(Some(n))
+    }
+
+    fn atom(&mut self) -> anyhow::Result<Node> {
+        let c = self.chars.next().expect("character should be present");
+        let node = match c {
+            '(' => {
+                if self.chars.next_if_eq(&'?').is_some() {
+                    ensure!(
+                        self.chars.next_if_eq(&':').is_some(),
+                        "only non-capturing groups `(?:...)` are supported"
+                    );
+                }
+                let node = self.alternation()?;
+                ensure!(
+                    self.chars.next_if_eq(&')').is_some(),
+                    "group should be closed by `)`"
+                );
+                node
+            }
+            '[' => Node::Class(self.class()?),
+            '.' => Node::Class(CharSet::new(vec![PRINTABLE])),
+            '^' | '$' => Node::Empty,
+            '\\' => match self.escape()? {
+                Escape::Char(c) => Node::Literal(c),
+                Escape::Class(set) => Node::Class(set),
+            },
+            '*' | '+' | '?' | '{' => bail!("nothing to repeat before {c:?}"),
+            c => Node::Literal(c),
+        };
+        Ok(node)
+    }
+
+    fn class(&mut self) -> anyhow::Result<CharSet> {
+        let negated = self.chars.next_if_eq(&'^').is_some();
+        let mut ranges = Vec::new();
+        let mut is_first = true;
+        loop {
+            let c = self
+                .chars
+                .next()
+                .context("character class should be closed by `]`")?;
+            if c == ']' && !is_first {
+                break;
+            }
+            is_first = false;
+            let start = if c == '\\' {
+                match self.escape()? {
+                    Escape::Char(c) => c,
+                    Escape::Class(set) => {
+                        ranges.extend(set.ranges);
+                        continue;
+                    }
+                }
+            } else {
+                c
+            };
+            let lookahead = self.chars.clone().take(2).collect::<Vec<_>>();
+            if matches!(lookahead[..], ['-', end] if end != ']') {
+                self.chars.next();
+                let end = match self.chars.next() {
+                    Some('\\') => match self.escape()? {
+                        Escape::Char(c) => c,
+                        Escape::Class(_) => bail!("invalid range in character class"),
+                    },
+                    Some(c) => c,
+                    None => unreachable!(),
+                };
+                ensure!(
+                    start <= end,
+                    "invalid range `{start}-{end}` in character class"
+                );
+                ranges.push((start, end));
+            } else {
+                ranges.push((start, start));
+            }
+        }
+        let set = CharSet::new(ranges);
+        let set = if negated { set.complement() } else { set };
+        ensure!(
+            set.len > 0,
+            "character class should match at least one character"
+        );
+        Ok(set)
+    }
+
+    fn escape(&mut self) -> anyhow::Result<Escape> {
+        let c = self
+            .chars
+            .next()
+            .context("pattern should not end with `\\`")?;
+        let escape = match c {
+            'd' | 'w' | 's' => Escape::Class(CharSet::perl(c)),
+            'D' | 'W' | 'S' => Escape::Class(CharSet::perl(c.to_ascii_lowercase()).complement()),
+            'n' => Escape::Char('\n'),
+            'r' => Escape::Char('\r'),
+            't' => Escape::Char('\t'),
+            'x' => Escape::Char(self.hex(Some(2))?),
+            'u' => {
+                if self.chars.next_if_eq(&'{').is_some() {
+                    let c = self.hex(None)?;
+                    ensure!(
+                        self.chars.next_if_eq(&'}').is_some(),
+                        "Unicode escape should be closed by `}}`"
+                    );
+                    Escape::Char(c)
+                } else {
+                    Escape::Char(self.hex(Some(4))?)
+                }
+            }
+            c if c.is_ascii_alphanumeric() => bail!("unknown escape `\\{c}`"),
+            c => Escape::Char(c),
+        };
+        Ok(escape)
+    }
+
+    fn hex(&mut self, len: Option<usize>) -> anyhow::Result<char> {
+        let mut digits = String::new();
+        while len.is_none_or(|len| digits.len() < len) {
+            match self.chars.next_if(char::is_ascii_hexdigit) {
+                Some(c) => digits.push(c),
+                None => break,
+            }
+        }
+        if digits.is_empty() || len.is_some_and(|len| digits.len() != len) {
+            bail!("invalid hexadecimal escape");
+        }
+        u32::from_str_radix(&digits, 16)
+            .ok()
+            .and_then(char::from_u32)
+            .with_context(|| format!("invalid code point `{digits}`"))
+    }
+}
+
+enum Escape {
+    Char(char),
+    Class(CharSet),
+}
+
+/// A set of characters which consists of disjoint inclusive ranges.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct CharSet {
+    ranges: Vec<(char, char)>,
+    len: u64,
+}
+
+impl CharSet {
+    fn new(mut ranges: Vec<(char, char)>) -> Self {
+        ranges.sort_unstable();
+        let mut merged: Vec<(char, char)> = Vec::with_capacity(ranges.len());
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some(last) if u32::from(start) <= u32::from(last.1) + 1 => {
+                    last.1 = last.1.max(end);
+                }
+                _ => merged.push((start, end)),
+            }
+        }
+        let len = merged
+            .iter()
+            .map(|&(start, end)| u64::from(Self::count(start, end)))
+            .sum();
+        Self {
+            ranges: merged,
+            len,
+        }
+    }
+
+    /// Returns the set of `\d`, `\w` or `\s`.
+    fn perl(c: char) -> Self {
+        match c {
+            'd' => Self::new(vec![('0', '9')]),
+            'w' => Self::new(vec![('0', '9'), ('A', 'Z'), ('_', '_'), ('a', 'z')]),
+            _ => Self::new(vec![('\t', '\r'), (' ', ' ')]),
+        }
+    }
+
+    // Returns the number of characters from `start` to `end`, which excludes
+    // surrogate code points.
+    const fn count(start: char, end: char) -> u32 {
+        (end as u32 - start as u32 + 1)
+            - ((end >= '\u{e000}' && start <= '\u{d7ff}') as u32 * 0x800)
+    }
+
+    /// Returns the printable ASCII characters which are not in the set.
+    fn complement(&self) -> Self {
+        let (first, last) = (u32::from(PRINTABLE.0), u32::from(PRINTABLE.1));
+        let ranges = (first..=last)
+            .filter_map(char::from_u32)
+            .filter(|c| !self.contains(*c))
+            .map(|c| (c, c))
+            .collect();
+        Self::new(ranges)
+    }
+
+    fn contains(&self, c: char) -> bool {
+        self.ranges
+            .iter()
+            .any(|&(start, end)| (start..=end).contains(&c))
+    }
+
+    fn sample(&self, rng: &mut Rng) -> char {
+        let mut index = rng.below(self.len);
+        for &(start, end) in &self.ranges {
+            let count = u64::from(Self::count(start, end));
+            if index < count {
+                let mut code =
+                    u32::from(start) + u32::try_from(index).expect("index should fit in `u32`");
+                if start <= '\u{d7ff}' && code > 0xd7ff {
+                    code += 0x800;
+                }
+                return char::from_u32(code).expect("code point should be a valid character");
+            }
+            index -= count;
+        }
+        unreachable!("index should be less than the number of characters")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::test_rng;
+
+    fn generate(pattern: &str) -> Vec<String> {
+        let regex = pattern.parse::<Regex>().unwrap();
+        let mut rng = test_rng();
+        (0..100).map(|_| regex.generate(&mut rng, 8)).collect()
+    }
+
+    #[test]
+    fn literal_and_class() {
+        for s in generate(r"[A-Z]{3}-\d{4}") {
+            let (letters, digits) = s.split_once('-').unwrap();
+            assert_eq!(letters.len(), 3);
+            assert!(letters.chars().all(|c| c.is_ascii_uppercase()));
+            assert_eq!(digits.len(), 4);
+            assert!(digits.chars().all(|c| c.is_ascii_digit()));
+        }
+    }
+
+    #[test]
+    fn alternation_and_group() {
+        let strings = generate("^(?:foo|ba(r|z))+$");
+        assert!(strings.iter().all(|s| !s.is_empty() && s.len() <= 24));
+        assert!(strings.iter().any(|s| s.contains("foo")));
+        assert!(strings.iter().any(|s| s.contains("bar")));
+        assert!(strings.iter().any(|s| s.contains("baz")));
+    }
+
+    #[test]
+    fn repetition() {
+        for s in generate("a*b{2,}c?d{1,3}") {
+            let count = |c| s.chars().filter(|&x| x == c).count();
+            assert!(count('a') <= 8);
+            assert!((2..=8).contains(&count('b')));
+            assert!(count('c') <= 1);
+            assert!((1..=3).contains(&count('d')));
+        }
+    }
+
+    #[test]
+    fn negated_class() {
+        for s in generate(r"[^a-z\d]\W") {
+            assert!(s.chars().all(|c| (' '..='~').contains(&c)));
+            assert!(
+                !s.chars()
+                    .any(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+            );
+        }
+    }
+
+    #[test]
+    fn unicode_range() {
+        for s in generate(r"[ぁ-ゖ]\u{1F600}[\u{D7FF}-\u{E000}]") {
+            let chars = s.chars().collect::<Vec<_>>();
+            assert!(('ぁ'..='ゖ').contains(&chars[0]));
+            assert_eq!(chars[1], '😀');
+            assert!(['\u{d7ff}', '\u{e000}'].contains(&chars[2]));
+        }
+    }
+
+    #[test]
+    fn char_set() {
+        let set = CharSet::new(vec![('a', 'c'), ('b', 'e'), ('x', 'x')]);
+        assert_eq!(set.ranges, [('a', 'e'), ('x', 'x')]);
+        assert_eq!(set.len, 6);
+        assert_eq!(CharSet::new(vec![('\u{d7ff}', '\u{e000}')]).len, 2);
+    }
+
+    #[test]
+    fn invalid_pattern() {
+        for pattern in [
+            "(a", "a)", "[a", "*a", "a{3,1}", "[z-a]", r"\q", "(?=a)", r"[^ -~]",
+        ] {
+            assert!(pattern.parse::<Regex>().is_err(), "{pattern}");
+        }
+    }
+}
@@ -59,8 +59,102 @@ pub enum Rng {
     Xoshiro512StarStar(Xoshiro512StarStar),
 }
 
-impl Rng {
-    pub fn fill_bytes(&mut self, dst: &mut [u8]) {
+impl RngCore for Rng {
+    fn next_u32(&mut self) -> u32 {
+        match self {
+            Self::ChaCha8(rng) => rng.next_u32(),
+            Self::ChaCha12(rng) => rng.next_u32(),
+            Self::ChaCha20(rng) => rng.next_u32(),
+            #[cfg(feature = "hc")]
+            Self::Hc128(rng) => rng.next_u32(),
+            #[cfg(feature = "isaac")]
+            Self::Isaac(rng) => rng.next_u32(),
+            #[cfg(feature = "isaac")]
+            Self::Isaac64(rng) => rng.next_u32(),
+            #[cfg(feature = "mt")]
+            Self::Mt(rng) => rng.next_u32(),
+            #[cfg(feature = "mt")]
+            Self::Mt64(rng) => rng.next_u32(),
+            #[cfg(feature = "pcg")]
+            Self::Pcg32(rng) => rng.next_u32(),
+            #[cfg(feature = "pcg")]
+            Self::Pcg64(rng) => rng.next_u32(),
+            #[cfg(feature = "pcg")]
+            Self::Pcg64Dxsm(rng) => rng.next_u32(),
+            #[cfg(feature = "pcg")]
+            Self::Pcg64Mcg(rng) => rng.next_u32(),
+            #[cfg(feature = "sfc")]
+            Self::Sfc32(rng) => rng.next_u32(),
+            #[cfg(feature = "sfc")]
+            Self::Sfc64(rng) => rng.next_u32(),
+            Self::SplitMix64(rng) => rng.next_u32(),
+            #[cfg(feature = "xorshift")]
+            Self::XorShift(rng) => rng.next_u32(),
+            Self::Xoroshiro64Star(rng) => rng.next_u32(),
+            Self::Xoroshiro64StarStar(rng) => rng.next_u32(),
+            Self::Xoroshiro128Plus(rng) => rng.next_u32(),
+            Self::Xoroshiro128PlusPlus(rng) => rng.next_u32(),
+            Self::Xoroshiro128StarStar(rng) => rng.next_u32(),
+            Self::Xoshiro128Plus(rng) => rng.next_u32(),
+            Self::Xoshiro128PlusPlus(rng) => rng.next_u32(),
+            Self::Xoshiro128StarStar(rng) => rng.next_u32(),
+            Self::Xoshiro256Plus(rng) => rng.next_u32(),
+            Self::Xoshiro256PlusPlus(rng) => rng.next_u32(),
+            Self::Xoshiro256StarStar(rng) => rng.next_u32(),
+            Self::Xoshiro512Plus(rng) => rng.next_u32(),
+            Self::Xoshiro512PlusPlus(rng) => rng.next_u32(),
+            Self::Xoshiro512StarStar(rng) => rng.next_u32(),
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        match self {
+            Self::ChaCha8(rng) => rng.next_u64(),
+            Self::ChaCha12(rng) => rng.next_u64(),
+            Self::ChaCha20(rng) => rng.next_u64(),
+            #[cfg(feature = "hc")]
+            Self::Hc128(rng) => rng.next_u64(),
+            #[cfg(feature = "isaac")]
+            Self::Isaac(rng) => rng.next_u64(),
+            #[cfg(feature = "isaac")]
+            Self::Isaac64(rng) => rng.next_u64(),
+            #[cfg(feature = "mt")]
+            Self::Mt(rng) => rng.next_u64(),
+            #[cfg(feature = "mt")]
+            Self::Mt64(rng) => rng.next_u64(),
+            #[cfg(feature = "pcg")]
+            Self::Pcg32(rng) => rng.next_u64(),
+            #[cfg(feature = "pcg")]
+            Self::Pcg64(rng) => rng.next_u64(),
+            #[cfg(feature = "pcg")]
+            Self::Pcg64Dxsm(rng) => rng.next_u64(),
+            #[cfg(feature = "pcg")]
+            Self::Pcg64Mcg(rng) => rng.next_u64(),
+            #[cfg(feature = "sfc")]
+            Self::Sfc32(rng) => rng.next_u64(),
+            #[cfg(feature = "sfc")]
+            Self::Sfc64(rng) => rng.next_u64(),
+            Self::SplitMix64(rng) => rng.next_u64(),
+            #[cfg(feature = "xorshift")]
+            Self::XorShift(rng) => rng.next_u64(),
+            Self::Xoroshiro64Star(rng) => rng.next_u64(),
+            Self::Xoroshiro64StarStar(rng) => rng.next_u64(),
+            Self::Xoroshiro128Plus(rng) => rng.next_u64(),
+            Self::Xoroshiro128PlusPlus(rng) => rng.next_u64(),
+            Self::Xoroshiro128StarStar(rng) => rng.next_u64(),
+            Self::Xoshiro128Plus(rng) => rng.next_u64(),
+            Self::Xoshiro128PlusPlus(rng) => rng.next_u64(),
+            Self::Xoshiro128StarStar(rng) => rng.next_u64(),
+            Self::Xoshiro256Plus(rng) => rng.next_u64(),
+            Self::Xoshiro256PlusPlus(rng) => rng.next_u64(),
+            Self::Xoshiro256StarStar(rng) => rng.next_u64(),
+            Self::Xoshiro512Plus(rng) => rng.next_u64(),
+            Self::Xoshiro512PlusPlus(rng) => rng.next_u64(),
+            Self::Xoshiro512StarStar(rng) => rng.next_u64(),
+        }
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
         match self {
             Self::ChaCha8(rng) => rng.fill_bytes(dst),
             Self::ChaCha12(rng) => rng.fill_bytes(dst),
@@ -106,7 +200,9 @@ impl Rng {
             Self::Xoshiro512StarStar(rng) => rng.fill_bytes(dst),
         }
     }
+}
 
+impl Rng {
     pub fn seed_from_u64(rng: &cli::Rng, state: u64) -> Self {
         match rng {
             cli::Rng::ChaCha8 => Self::ChaCha8(ChaCha8Rng::seed_from_u64(state)),
@@ -252,4 +348,96 @@ impl Rng {
             )),
         }
     }
+
+    /// Returns a random `u128`.
+    pub fn next_u128(&mut self) -> u128 {
+        (u128::from(self.next_u64()) << 64) | u128::from(self.next_u64())
+    }
+
+    /// Returns a uniformly distributed random `f64` in `[0, 1)`.
+    ///
+    /// All 53 bits of the significand are random.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn next_f64(&mut self) -> f64 {
+        // 2^-53.
+        const SCALE: f64 = 1.0 / (1_u64 << f64::MANTISSA_DIGITS) as f64;
+
+        (self.next_u64() >> (u64::BITS - f64::MANTISSA_DIGITS)) as f64 * SCALE
+    }
+
+    /// Returns a uniformly distributed random integer in `0..bound`.
+    ///
+    /// This uses Lemire's nearly divisionless method, so the result is not
+    /// biased.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bound` is zero.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert_ne!(bound, u64::MIN, "bound should be greater than zero");
+        let mut m = u128::from(self.next_u64()) * u128::from(bound);
+        if (m as u64) < bound {
+            let threshold = bound.wrapping_neg() % bound;
+            while (m as u64) < threshold {
+                m = u128::from(self.next_u64()) * u128::from(bound);
+            }
+        }
+        u64::try_from(m >> 64).expect("high half should fit in `u64`")
+    }
+
+    /// Returns a uniformly distributed random integer in `0..bound`.
+    ///
+    /// Bounds which do not fit in `u64` are handled by bitmask rejection
+    /// sampling, so the result is not biased.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bound` is zero.
+    pub fn below_u128(&mut self, bound: u128) -> u128 {
+        assert_ne!(bound, u128::MIN, "bound should be greater than zero");
+        if let Ok(bound) = u64::try_from(bound) {
+            return self.below(bound).into();
+        }
+        let mask = u128::MAX >> (bound - 1).leading_zeros();
+        loop {
+            let n = self.next_u128() & mask;
+            if n < bound {
+                return n;
+            }
+        }
+    }
+}
+
+/// Returns the default RNG seeded with 0, which is used by tests.
+#[cfg(test)]
+pub fn test_rng() -> Rng {
+    Rng::seed_from_u64(&cli::Rng::default(), u64::MIN)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn below() {
+        let mut rng = Rng::seed_from_u64(&cli::Rng::default(), u64::MIN);
+        let mut counts = [usize::MIN; 6];
+        for _ in 0..6000 {
+            counts[usize::try_from(rng.below(6)).unwrap()] += 1;
+        }
+        assert!(counts.iter().all(|&c| (800..1200).contains(&c)));
+        assert_eq!(rng.below(1), u64::MIN);
+        assert!(rng.below(u64::MAX) < u64::MAX);
+    }
+
+    #[test]
+    fn below_u128() {
+        let mut rng = Rng::seed_from_u64(&cli::Rng::default(), u64::MIN);
+        let bound = (1 << 100) + 1;
+        for _ in 0..1000 {
+            assert!(rng.below_u128(bound) < bound);
+        }
+        assert_eq!(rng.below_u128(1), u128::MIN);
+    }
 }
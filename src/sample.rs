@@ -0,0 +1,302 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+use anyhow::{Context, ensure};
+
+use crate::rng::Rng;
+
+/// A reservoir which holds a uniform random sample of the records pushed so
+/// far.
+///
+/// This implements Algorithm L, which skips the records which are not selected
+/// without drawing random numbers for them.
+#[derive(Debug)]
+pub struct Reservoir {
+    size: usize,
+    items: Vec<(u64, Vec<u8>)>,
+    weight: f64,
+    next: u64,
+    seen: u64,
+}
+
+impl Reservoir {
+    /// Creates a new `Reservoir` which holds at most `size` records.
+    pub const fn new(size: usize) -> Self {
+        Self {
+            size,
+            items: Vec::new(),
+            weight: 1.0,
+            next: 0,
+            seen: 0,
+        }
+    }
+
+    /// Pushes a record.
+    pub fn push(&mut self, rng: &mut Rng, record: Vec<u8>) {
+        let index = self.seen;
+        self.seen += 1;
+        if self.items.len() < self.size {
+            self.items.push((index, record));
+            if self.items.len() == self.size {
+                self.weight = self.next_weight(rng);
+                self.next = index + self.skip(rng);
+            }
+        } else if index == self.next && self.size > 0 {
+            let len = u64::try_from(self.size).expect("size should fit in `u64`");
+            let slot = usize::try_from(rng.below(len)).expect("index should fit in `usize`");
+            self.items[slot] = (index, record);
+            self.weight *= self.next_weight(rng);
+            self.next += self.skip(rng);
+        }
+    }
+
+    /// Returns the sampled records in the order in which they were pushed.
+    pub fn into_records(mut self) -> Vec<Vec<u8>> {
+        self.items.sort_unstable_by_key(|&(index, _)| index);
+        self.items.into_iter().map(|(_, record)| record).collect()
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn next_weight(&self, rng: &mut Rng) -> f64 {
+        (random(rng).ln() / self.size as f64).exp()
+    }
+
+    // Returns the distance to the next record which is selected.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn skip(&self, rng: &mut Rng) -> u64 {
+        (random(rng).ln() / (-self.weight).ln_1p()).floor() as u64 + 1
+    }
+}
+
+/// A reservoir which holds a weighted random sample of the records pushed so
+/// far.
+///
+/// This implements Algorithm A-ExpJ, in which each record is selected with
+/// probability proportional to its weight.
+#[derive(Debug)]
+pub struct WeightedReservoir {
+    size: usize,
+    heap: BinaryHeap<Entry>,
+    jump: f64,
+    seen: u64,
+}
+
+#[derive(Debug)]
+struct Entry {
+    // The logarithm of the key, which the entry with the minimum of is
+    // replaced.
+    key: f64,
+    index: u64,
+    record: Vec<u8>,
+}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Entry {}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Entry {
+    // The order is reversed so that `BinaryHeap` is a min-heap.
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.key.total_cmp(&self.key)
+    }
+}
+
+impl WeightedReservoir {
+    /// Creates a new `WeightedReservoir` which holds at most `size` records.
+    pub const fn new(size: usize) -> Self {
+        Self {
+            size,
+            heap: BinaryHeap::new(),
+            jump: 0.0,
+            seen: 0,
+        }
+    }
+
+    /// Pushes a record with `weight`.
+    ///
+    /// A record with zero weight is never selected.
+    pub fn push(&mut self, rng: &mut Rng, record: Vec<u8>, weight: f64) {
+        let index = self.seen;
+        self.seen += 1;
+        if weight <= 0.0 || self.size == 0 {
+            return;
+        }
+        if self.heap.len() < self.size {
+            let key = random(rng).ln() / weight;
+            self.heap.push(Entry { key, index, record });
+            if self.heap.len() == self.size {
+                self.jump = self.next_jump(rng);
+            }
+            return;
+        }
+        self.jump -= weight;
+        if self.jump <= 0.0 {
+            let min = self.heap.peek().expect("reservoir should not be empty").key;
+            // The key of the new entry is drawn from the range in which it is
+            // greater than the minimum.
+            let threshold = (min * weight).exp();
+            let key = random(rng).mul_add(1.0 - threshold, threshold).ln() / weight;
+            self.heap.pop();
+            self.heap.push(Entry { key, index, record });
+            self.jump = self.next_jump(rng);
+        }
+    }
+
+    /// Returns the sampled records in the order in which they were pushed.
+    pub fn into_records(self) -> Vec<Vec<u8>> {
+        let mut entries = self.heap.into_vec();
+        entries.sort_unstable_by_key(|entry| entry.index);
+        entries.into_iter().map(|entry| entry.record).collect()
+    }
+
+    fn next_jump(&self, rng: &mut Rng) -> f64 {
+        let min = self.heap.peek().expect("reservoir should not be empty").key;
+        random(rng).ln() / min
+    }
+}
+
+/// Returns a uniformly distributed random number in the open interval (0, 1).
+fn random(rng: &mut Rng) -> f64 {
+    loop {
+        let n = rng.next_f64();
+        if n > 0.0 {
+            return n;
+        }
+    }
+}
+
+/// Parses the weight in the field `column` (1-based) of `record`.
+///
+/// If `separator` is [`None`], the fields are separated by whitespace.
+pub fn parse_weight(record: &[u8], column: usize, separator: Option<&str>) -> anyhow::Result<f64> {
+    let record = String::from_utf8_lossy(record);
+    let field = separator
+        .map_or_else(
+            || record.split_whitespace().nth(column - 1),
+            |separator| record.split(separator).nth(column - 1),
+        )
+        .with_context(|| format!("record does not have field {column}"))?;
+    let weight = field
+        .trim()
+        .parse::<f64>()
+        .with_context(|| format!("invalid weight `{field}`"))?;
+    ensure!(
+        weight.is_finite() && weight >= 0.0,
+        "weight should be a non-negative finite number"
+    );
+    Ok(weight)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::test_rng;
+
+    #[test]
+    fn reservoir() {
+        let mut reservoir = Reservoir::new(10);
+        let mut rng = test_rng();
+        for i in 0..1000_u32 {
+            reservoir.push(&mut rng, i.to_be_bytes().to_vec());
+        }
+        let records = reservoir.into_records();
+        assert_eq!(records.len(), 10);
+        assert!(records.is_sorted());
+        assert!(
+            records
+                .iter()
+                .any(|record| record > &100_u32.to_be_bytes().to_vec())
+        );
+    }
+
+    #[test]
+    fn reservoir_is_uniform() {
+        let mut counts = [0; 10];
+        let mut rng = test_rng();
+        for _ in 0..10000 {
+            let mut reservoir = Reservoir::new(3);
+            for i in 0..10_u8 {
+                reservoir.push(&mut rng, vec![i]);
+            }
+            for record in reservoir.into_records() {
+                counts[usize::from(record[0])] += 1;
+            }
+        }
+        // Each record is selected with probability 0.3.
+        assert!(
+            counts.iter().all(|&count| (2700..3300).contains(&count)),
+            "{counts:?}"
+        );
+    }
+
+    #[test]
+    fn small_input() {
+        let mut reservoir = Reservoir::new(10);
+        reservoir.push(&mut test_rng(), b"a".to_vec());
+        assert_eq!(reservoir.into_records(), [b"a"]);
+        let mut reservoir = Reservoir::new(0);
+        reservoir.push(&mut test_rng(), b"a".to_vec());
+        assert!(reservoir.into_records().is_empty());
+    }
+
+    #[test]
+    fn weighted_reservoir() {
+        let mut counts = [0; 3];
+        let mut rng = test_rng();
+        for _ in 0..10000 {
+            let mut reservoir = WeightedReservoir::new(1);
+            for (i, weight) in [1.0, 0.0, 3.0].into_iter().enumerate() {
+                reservoir.push(&mut rng, vec![u8::try_from(i).unwrap()], weight);
+            }
+            for record in reservoir.into_records() {
+                counts[usize::from(record[0])] += 1;
+            }
+        }
+        assert!((2200..2800).contains(&counts[0]), "{counts:?}");
+        assert_eq!(counts[1], 0);
+        assert!((7200..7800).contains(&counts[2]), "{counts:?}");
+    }
+
+    #[test]
+    fn weighted_reservoir_jumps() {
+        let mut counts = [0; 100];
+        let mut rng = test_rng();
+        for _ in 0..2000 {
+            let mut reservoir = WeightedReservoir::new(5);
+            for i in 0..100_u8 {
+                let weight = if i < 50 { 1.0 } else { 3.0 };
+                reservoir.push(&mut rng, vec![i], weight);
+            }
+            for record in reservoir.into_records() {
+                counts[usize::from(record[0])] += 1;
+            }
+        }
+        let (light, heavy) = counts.split_at(50);
+        let (light, heavy) = (light.iter().sum::<i32>(), heavy.iter().sum::<i32>());
+        assert_eq!(light + heavy, 10000);
+        assert!(heavy > light * 2, "{light} {heavy}");
+    }
+
+    #[test]
+    fn parse_weight() {
+        assert!((super::parse_weight(b"a 2.5 b", 2, None).unwrap() - 2.5).abs() < f64::EPSILON);
+        assert!((super::parse_weight(b"a,,3", 3, Some(",")).unwrap() - 3.0).abs() < f64::EPSILON);
+        assert!(super::parse_weight(b"a 1", 3, None).is_err());
+        assert!(super::parse_weight(b"a x", 2, None).is_err());
+        assert!(super::parse_weight(b"a -1", 2, None).is_err());
+        assert!(super::parse_weight(b"a inf", 2, None).is_err());
+    }
+}
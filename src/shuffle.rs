@@ -0,0 +1,202 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::io::{self, BufRead, BufReader, BufWriter, Seek, Write};
+
+use anyhow::Context;
+
+use crate::rng::Rng;
+
+/// The number of temporary files which the records are distributed to when the
+/// input does not fit in the buffer.
+const BUCKETS: u64 = 256;
+
+/// A shuffler of records which are separated by a delimiter.
+///
+/// If the input is larger than the buffer, the records are distributed to
+/// temporary files uniformly at random, and each file is shuffled recursively.
+/// The concatenation of the shuffled files is a uniformly random permutation of
+/// the input.
+#[derive(Debug)]
+pub struct Shuffler {
+    delimiter: u8,
+    buffer_size: usize,
+    remaining: u64,
+}
+
+impl Shuffler {
+    /// Creates a new `Shuffler`.
+    ///
+    /// At most `limit` records are output.
+    pub const fn new(delimiter: u8, buffer_size: usize, limit: u64) -> Self {
+        Self {
+            delimiter,
+            buffer_size,
+            remaining: limit,
+        }
+    }
+
+    /// Shuffles the records read from `reader` and passes each of them to
+    /// `write` without the delimiter.
+    pub fn shuffle(
+        &mut self,
+        rng: &mut Rng,
+        mut reader: impl BufRead,
+        write: &mut dyn FnMut(&[u8]) -> io::Result<()>,
+    ) -> anyhow::Result<()> {
+        let mut records = Vec::new();
+        let mut size = 0;
+        while let Some(record) = read_record(&mut reader, self.delimiter)? {
+            size += record.len() + size_of::<Vec<u8>>();
+            records.push(record);
+            if size > self.buffer_size && records.len() > 1 {
+                return self.shuffle_external(rng, records, reader, write);
+            }
+        }
+
+        // Since only the first records are output, a partial Fisher-Yates
+        // shuffle is enough.
+        let len = u64::try_from(records.len())?;
+        for i in 0..len.min(self.remaining) {
+            let j = i + rng.below(len - i);
+            let (i, j) = (usize::try_from(i)?, usize::try_from(j)?);
+            records.swap(i, j);
+            write(&records[i]).context("could not write shuffled records")?;
+            self.remaining -= 1;
+        }
+        Ok(())
+    }
+
+    fn shuffle_external(
+        &mut self,
+        rng: &mut Rng,
+        records: Vec<Vec<u8>>,
+        mut reader: impl BufRead,
+        write: &mut dyn FnMut(&[u8]) -> io::Result<()>,
+    ) -> anyhow::Result<()> {
+        let mut buckets = (0..BUCKETS)
+            .map(|_| tempfile::tempfile().map(BufWriter::new))
+            .collect::<io::Result<Vec<_>>>()
+            .context("could not create a temporary file")?;
+        let delimiter = self.delimiter;
+        let mut distribute = |record: &[u8]| -> anyhow::Result<()> {
+            let bucket = &mut buckets[usize::try_from(rng.below(BUCKETS))?];
+            bucket
+                .write_all(record)
+                .and_then(|()| bucket.write_all(&[delimiter]))
+                .context("could not write to a temporary file")
+        };
+        for record in records {
+            distribute(&record)?;
+        }
+        while let Some(record) = read_record(&mut reader, self.delimiter)? {
+            distribute(&record)?;
+        }
+
+        for bucket in buckets {
+            if self.remaining == 0 {
+                break;
+            }
+            let mut file = bucket
+                .into_inner()
+                .map_err(io::IntoInnerError::into_error)
+                .context("could not write to a temporary file")?;
+            file.rewind().context("could not rewind a temporary file")?;
+            self.shuffle(rng, BufReader::new(file), write)?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads a record which is terminated by `delimiter` or the end of the input.
+///
+/// The delimiter is removed from the record. Returns [`None`] at the end of
+/// the input.
+pub fn read_record(reader: &mut impl BufRead, delimiter: u8) -> anyhow::Result<Option<Vec<u8>>> {
+    let mut record = Vec::new();
+    if reader
+        .read_until(delimiter, &mut record)
+        .context("could not read records")?
+        == 0
+    {
+        return Ok(None);
+    }
+    if record.last() == Some(&delimiter) {
+        record.pop();
+    }
+    Ok(Some(record))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::test_rng;
+
+    fn shuffle(input: &[u8], buffer_size: usize, limit: u64) -> Vec<Vec<u8>> {
+        let mut output = Vec::new();
+        Shuffler::new(b'\n', buffer_size, limit)
+            .shuffle(&mut test_rng(), input, &mut |record| {
+                output.push(record.to_vec());
+                Ok(())
+            })
+            .unwrap();
+        output
+    }
+
+    fn input(len: usize) -> Vec<u8> {
+        let mut input = (0..len)
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        input.push('\n');
+        input.into_bytes()
+    }
+
+    #[test]
+    fn in_memory() {
+        let input = input(1000);
+        let mut output = shuffle(&input, usize::MAX, u64::MAX);
+        assert_eq!(output.len(), 1000);
+        assert_ne!(
+            output.concat(),
+            input.split(|&b| b == b'\n').collect::<Vec<_>>().concat()
+        );
+        output.sort_by_key(|record| String::from_utf8_lossy(record).parse::<u32>().unwrap());
+        assert_eq!(
+            output,
+            (0..1000)
+                .map(|i| i.to_string().into_bytes())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn external() {
+        let input = input(10000);
+        let mut output = shuffle(&input, 10000, u64::MAX);
+        assert_eq!(output.len(), 10000);
+        output.sort_by_key(|record| String::from_utf8_lossy(record).parse::<u32>().unwrap());
+        assert_eq!(
+            output,
+            (0..10000)
+                .map(|i| i.to_string().into_bytes())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn limit() {
+        assert_eq!(shuffle(&input(100), usize::MAX, 10).len(), 10);
+        assert_eq!(shuffle(&input(10000), 10000, 10).len(), 10);
+        assert_eq!(shuffle(&input(5), usize::MAX, 10).len(), 5);
+    }
+
+    #[test]
+    fn without_trailing_delimiter() {
+        let mut output = shuffle(b"a\nb\nc", usize::MAX, u64::MAX);
+        output.sort();
+        assert_eq!(output, [b"a", b"b", b"c"]);
+        assert!(shuffle(b"", usize::MAX, u64::MAX).is_empty());
+    }
+}
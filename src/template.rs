@@ -0,0 +1,273 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::{fmt::Write, str::FromStr};
+
+use anyhow::{Context, bail, ensure};
+#[cfg(feature = "base64")]
+use base64::Engine;
+#[cfg(any(feature = "base64", feature = "hex"))]
+use rand_core::RngCore;
+
+use crate::{
+    cli::UuidStyle,
+    id::{self, BASE32, BASE62},
+    int::IntRange,
+    rng::Rng,
+    uuid::Uuid,
+};
+
+/// A template which consists of literal text and placeholders.
+///
+/// A placeholder is `{NAME}` or `{NAME:ARG}`, and `{{` and `}}` are literal `{`
+/// and `}`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Template(Vec<Segment>);
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Segment {
+    Literal(String),
+    Field(Field),
+}
+
+/// A placeholder which is replaced with a random value.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Field {
+    /// The specified number of characters of hex string.
+    #[cfg(feature = "hex")]
+    Hex(usize),
+
+    /// The specified number of characters of unpadded base64.
+    #[cfg(feature = "base64")]
+    Base64(usize),
+
+    /// The specified number of characters of unpadded URL-safe base64.
+    #[cfg(feature = "base64")]
+    Base64Url(usize),
+
+    /// The specified number of characters of base32.
+    Base32(usize),
+
+    /// The specified number of characters of base62.
+    Base62(usize),
+
+    /// An integer in the range.
+    Int(IntRange),
+
+    /// A version 4 UUID.
+    Uuid,
+}
+
+impl Template {
+    /// Renders the template by replacing each placeholder with a random value.
+    pub fn render(&self, rng: &mut Rng) -> String {
+        let mut s = String::new();
+        for segment in &self.0 {
+            match segment {
+                Segment::Literal(text) => s.push_str(text),
+                Segment::Field(field) => field.render(rng, &mut s),
+            }
+        }
+        s
+    }
+}
+
+impl Field {
+    fn render(&self, rng: &mut Rng, s: &mut String) {
+        match self {
+            #[cfg(feature = "hex")]
+            Self::Hex(len) => {
+                let bytes = random_bytes(rng, len.div_ceil(2));
+                s.push_str(&faster_hex::hex_string(&bytes)[..*len]);
+            }
+            #[cfg(feature = "base64")]
+            Self::Base64(len) => {
+                let bytes = random_bytes(rng, (len * 3).div_ceil(4));
+                let encoded = base64::engine::general_purpose::STANDARD_NO_PAD.encode(bytes);
+                s.push_str(&encoded[..*len]);
+            }
+            #[cfg(feature = "base64")]
+            Self::Base64Url(len) => {
+                let bytes = random_bytes(rng, (len * 3).div_ceil(4));
+                let encoded = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(bytes);
+                s.push_str(&encoded[..*len]);
+            }
+            Self::Base32(len) => s.push_str(&id::nanoid(rng, &BASE32.map(char::from), *len)),
+            Self::Base62(len) => s.push_str(&id::nanoid(rng, &BASE62.map(char::from), *len)),
+            Self::Int(range) => {
+                write!(s, "{}", range.sample(rng)).expect("writing to `String` should not fail");
+            }
+            Self::Uuid => s.push_str(&Uuid::new_v4(rng).encode(UuidStyle::Hyphenated)),
+        }
+    }
+}
+
+#[cfg(any(feature = "base64", feature = "hex"))]
+fn random_bytes(rng: &mut Rng, len: usize) -> Vec<u8> {
+    let mut bytes = vec![u8::MIN; len];
+    rng.fill_bytes(&mut bytes);
+    bytes
+}
+
+impl FromStr for Field {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, arg) = s
+            .split_once(':')
+            .map_or((s, None), |(name, arg)| (name, Some(arg)));
+        let len = || -> anyhow::Result<usize> {
+            let arg = arg.with_context(|| format!("placeholder `{name}` requires a length"))?;
+            let len = arg
+                .parse::<usize>()
+                .with_context(|| format!("invalid length `{arg}` of placeholder `{name}`"))?;
+            // This ensures that the number of random bytes for base64 can be
+            // computed without overflow.
+            ensure!(
+                len.checked_mul(3).is_some(),
+                "length `{len}` of placeholder `{name}` is too large"
+            );
+            Ok(len)
+        };
+        let field = match name {
+            #[cfg(feature = "hex")]
+            "hex" => Self::Hex(len()?),
+            #[cfg(feature = "base64")]
+            "base64" => Self::Base64(len()?),
+            #[cfg(feature = "base64")]
+            "base64url" => Self::Base64Url(len()?),
+            "base32" => Self::Base32(len()?),
+            "base62" => Self::Base62(len()?),
+            "int" => {
+                let arg = arg.context("placeholder `int` requires a range")?;
+                Self::Int(
+                    arg.parse()
+                        .with_context(|| format!("invalid range `{arg}` of placeholder `int`"))?,
+                )
+            }
+            "uuid" => {
+                ensure!(
+                    arg.is_none(),
+                    "placeholder `uuid` does not take an argument"
+                );
+                Self::Uuid
+            }
+            name => bail!("unknown placeholder `{name}`"),
+        };
+        Ok(field)
+    }
+}
+
+impl FromStr for Template {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut segments = Vec::new();
+        let mut text = String::new();
+        let mut chars = s.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.as_str().starts_with('{') => {
+                    chars.next();
+                    text.push('{');
+                }
+                '{' => {
+                    let (placeholder, rest) = chars
+                        .as_str()
+                        .split_once('}')
+                        .context("placeholder should be closed by `}`")?;
+                    if !text.is_empty() {
+                        segments.push(Segment::Literal(std::mem::take(&mut text)));
+                    }
+                    segments.push(Segment::Field(placeholder.parse()?));
+                    chars = rest.chars();
+                }
+                '}' => {
+                    ensure!(
+                        chars.next() == Some('}'),
+                        "unmatched `}}` should be escaped as `}}}}`"
+                    );
+                    text.push('}');
+                }
+                c => text.push(c),
+            }
+        }
+        if !text.is_empty() {
+            segments.push(Segment::Literal(text));
+        }
+        Ok(Self(segments))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::literal_string_with_formatting_args)]
+mod tests {
+    use super::*;
+    use crate::rng::test_rng;
+
+    #[test]
+    fn parse() {
+        let template = "key_{{live}}_{base62:24}-{int:1..6}{uuid}"
+            .parse::<Template>()
+            .unwrap();
+        assert_eq!(
+            template.0,
+            [
+                Segment::Literal("key_{live}_".into()),
+                Segment::Field(Field::Base62(24)),
+                Segment::Literal("-".into()),
+                Segment::Field(Field::Int("1..6".parse().unwrap())),
+                Segment::Field(Field::Uuid),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_invalid_template() {
+        for template in [
+            "{base62",
+            "a}b",
+            "{foo}",
+            "{base62}",
+            "{base62:x}",
+            "{base32:18446744073709551615}",
+            "{int}",
+            "{int:6..1}",
+            "{uuid:4}",
+        ] {
+            assert!(template.parse::<Template>().is_err(), "{template}");
+        }
+    }
+
+    #[test]
+    fn render() {
+        let template = "key_live_{base62:24}/{base32:5}/{int:-3..3}/{uuid}"
+            .parse::<Template>()
+            .unwrap();
+        let s = template.render(&mut test_rng());
+        let fields = s.split('/').collect::<Vec<_>>();
+        assert_eq!(fields[0].len(), 33);
+        assert!(fields[0][9..].bytes().all(|b| BASE62.contains(&b)));
+        assert_eq!(fields[1].len(), 5);
+        assert!(fields[1].bytes().all(|b| BASE32.contains(&b)));
+        assert!((-3..=3).contains(&fields[2].parse::<i8>().unwrap()));
+        assert_eq!(fields[3].len(), 36);
+        assert_eq!(template.render(&mut test_rng()), s);
+    }
+
+    #[cfg(all(feature = "base64", feature = "hex"))]
+    #[test]
+    fn render_encoded() {
+        let template = "{hex:7} {base64:5} {base64url:43}"
+            .parse::<Template>()
+            .unwrap();
+        let s = template.render(&mut test_rng());
+        let fields = s.split(' ').collect::<Vec<_>>();
+        assert_eq!(fields[0].len(), 7);
+        assert!(fields[0].bytes().all(|b| b.is_ascii_hexdigit()));
+        assert_eq!(fields[1].len(), 5);
+        assert_eq!(fields[2].len(), 43);
+        assert!(!fields[2].contains(['+', '/', '=']));
+    }
+}
@@ -0,0 +1,37 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+/// Returns the approximate probability that `count` values drawn uniformly at
+/// random from `space` values contain a duplicate.
+///
+/// This is the birthday bound `1 - exp(-n(n - 1) / 2N)`.
+#[allow(clippy::cast_precision_loss)]
+pub fn collision_probability(count: u64, space: f64) -> f64 {
+    let count = count as f64;
+    (-count * (count - 1.0) / (2.0 * space)).exp_m1().abs()
+}
+
+/// Returns the number of byte strings of `len` bytes as a floating-point
+/// number.
+pub fn byte_space(len: usize) -> f64 {
+    i32::try_from(len).map_or(f64::INFINITY, |len| 256_f64.powi(len))
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn collision_probability() {
+        assert!(super::collision_probability(1, 365.0).abs() < f64::EPSILON);
+        // The birthday problem.
+        let p = super::collision_probability(23, 365.0);
+        assert!((p - 0.5).abs() < 0.01);
+        assert!(super::collision_probability(2, super::byte_space(1000)).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn byte_space() {
+        assert!((super::byte_space(2) - 65536.0).abs() < f64::EPSILON);
+        assert!(super::byte_space(usize::MAX).is_infinite());
+    }
+}
@@ -0,0 +1,184 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::{fmt::Write, str::FromStr};
+
+use anyhow::{bail, ensure};
+use rand_core::RngCore;
+
+use crate::{cli::UuidStyle, rng::Rng};
+
+/// The maximum value of a 48-bit Unix timestamp in milliseconds.
+pub const MAX_TIMESTAMP: u64 = (1 << 48) - 1;
+
+/// A UUID defined in RFC 9562.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Uuid([u8; 16]);
+
+impl Uuid {
+    /// Creates a new version 4 UUID, which consists of random bits.
+    pub fn new_v4(rng: &mut Rng) -> Self {
+        let mut bytes = [u8::MIN; 16];
+        rng.fill_bytes(&mut bytes);
+        Self::with_version(bytes, 4)
+    }
+
+    /// Creates a new version 7 UUID, which consists of a 48-bit Unix timestamp
+    /// in milliseconds followed by random bits.
+    pub fn new_v7(rng: &mut Rng, timestamp: u64) -> anyhow::Result<Self> {
+        ensure!(
+            timestamp <= MAX_TIMESTAMP,
+            "timestamp {timestamp} does not fit in 48 bits"
+        );
+        let mut bytes = [u8::MIN; 16];
+        bytes[..6].copy_from_slice(&timestamp.to_be_bytes()[2..]);
+        rng.fill_bytes(&mut bytes[6..]);
+        Ok(Self::with_version(bytes, 7))
+    }
+
+    /// Creates a new version 8 UUID, which begins with `payload` and is
+    /// followed by random bits.
+    ///
+    /// The version and variant fields override the corresponding bits of
+    /// `payload`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `payload` is longer than 16 bytes.
+    pub fn new_v8(rng: &mut Rng, payload: &[u8]) -> Self {
+        let mut bytes = [u8::MIN; 16];
+        let (custom, random) = bytes.split_at_mut(payload.len());
+        custom.copy_from_slice(payload);
+        rng.fill_bytes(random);
+        Self::with_version(bytes, 8)
+    }
+
+    const fn with_version(mut bytes: [u8; 16], version: u8) -> Self {
+        bytes[6] = (version << 4) | (bytes[6] & 0x0f);
+        // The variant defined in RFC 9562.
+        bytes[8] = 0x80 | (bytes[8] & 0x3f);
+        Self(bytes)
+    }
+
+    /// Encodes the UUID in the specified style.
+    pub fn encode(&self, style: UuidStyle) -> String {
+        let mut s = String::with_capacity(45);
+        match style {
+            UuidStyle::Urn => s.push_str("urn:uuid:"),
+            UuidStyle::Braced => s.push('{'),
+            UuidStyle::Hyphenated | UuidStyle::Simple => {}
+        }
+        for (i, byte) in self.0.iter().enumerate() {
+            if matches!(i, 4 | 6 | 8 | 10) && style != UuidStyle::Simple {
+                s.push('-');
+            }
+            write!(s, "{byte:02x}").expect("writing to a string should not fail");
+        }
+        if style == UuidStyle::Braced {
+            s.push('}');
+        }
+        s
+    }
+}
+
+/// The custom bits of a version 8 UUID.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Payload(Vec<u8>);
+
+impl Payload {
+    /// Returns the bytes of the payload.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl FromStr for Payload {
+    type Err = anyhow::Error;
+
+    /// Parses the payload as hexadecimal digits.
+    ///
+    /// Hyphens are ignored.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s
+            .chars()
+            .filter(|&c| c != '-')
+            .map(|c| {
+                let Some(digit) = c.to_digit(16) else {
+                    bail!("payload contains a non-hexadecimal character {c:?}");
+                };
+                Ok(u8::try_from(digit).expect("hexadecimal digit should be less than 16"))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        ensure!(
+            digits.len() % 2 == 0,
+            "payload should have an even number of digits"
+        );
+        ensure!(digits.len() <= 32, "payload should be at most 16 bytes");
+        Ok(Self(digits.chunks(2).map(|d| (d[0] << 4) | d[1]).collect()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::test_rng;
+
+    #[test]
+    fn encode() {
+        let uuid = Uuid(*b"\x01\x23\x45\x67\x89\xab\xcd\xef\x01\x23\x45\x67\x89\xab\xcd\xef");
+        assert_eq!(
+            uuid.encode(UuidStyle::Hyphenated),
+            "01234567-89ab-cdef-0123-456789abcdef"
+        );
+        assert_eq!(
+            uuid.encode(UuidStyle::Simple),
+            "0123456789abcdef0123456789abcdef"
+        );
+        assert_eq!(
+            uuid.encode(UuidStyle::Urn),
+            "urn:uuid:01234567-89ab-cdef-0123-456789abcdef"
+        );
+        assert_eq!(
+            uuid.encode(UuidStyle::Braced),
+            "{01234567-89ab-cdef-0123-456789abcdef}"
+        );
+    }
+
+    #[test]
+    fn version_and_variant() {
+        let mut rng = test_rng();
+        for _ in 0..100 {
+            let uuid = Uuid::new_v4(&mut rng);
+            assert_eq!(uuid.0[6] >> 4, 4);
+            assert_eq!(uuid.0[8] >> 6, 0b10);
+        }
+    }
+
+    #[test]
+    fn v7() {
+        let uuid = Uuid::new_v7(&mut test_rng(), 0x0123_4567_89ab).unwrap();
+        assert_eq!(uuid.0[..6], [0x01, 0x23, 0x45, 0x67, 0x89, 0xab]);
+        assert_eq!(uuid.0[6] >> 4, 7);
+        assert!(Uuid::new_v7(&mut test_rng(), MAX_TIMESTAMP + 1).is_err());
+    }
+
+    #[test]
+    fn v8() {
+        let uuid = Uuid::new_v8(&mut test_rng(), &[0xff; 16]);
+        assert_eq!(
+            uuid.encode(UuidStyle::Simple),
+            "ffffffffffff8fffbfffffffffffffff"
+        );
+        let uuid = Uuid::new_v8(&mut test_rng(), &[0xab, 0xcd]);
+        assert_eq!(uuid.0[..2], [0xab, 0xcd]);
+    }
+
+    #[test]
+    fn parse_payload() {
+        assert_eq!("ab-CD".parse::<Payload>().unwrap().as_bytes(), [0xab, 0xcd]);
+        assert!("abc".parse::<Payload>().is_err());
+        assert!("xy".parse::<Payload>().is_err());
+        assert!("0".repeat(34).parse::<Payload>().is_err());
+    }
+}
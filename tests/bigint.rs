@@ -0,0 +1,124 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod utils;
+
+use predicates::prelude::predicate;
+
+#[test]
+fn bigint() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("bigint")
+        .arg("128")
+        .assert()
+        .success()
+        .stdout("339000780974591605396468798168694685649\n");
+}
+
+#[test]
+fn odd_bigint_as_hex() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("bigint")
+        .arg("--odd")
+        .arg("-t")
+        .arg("hex")
+        .arg("70")
+        .assert()
+        .success()
+        .stdout("2bf2d75435ca367fd1\n");
+}
+
+#[cfg(feature = "base64")]
+#[test]
+fn bigint_as_base64() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("bigint")
+        .arg("-t")
+        .arg("base64")
+        .arg("256")
+        .assert()
+        .success()
+        .stdout("9OgRGJF+Lf5SRUwRHCSAsP8JLO80NXRL8tdUNco2f9E=\n");
+}
+
+#[test]
+fn prime() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("prime")
+        .arg("64")
+        .assert()
+        .success()
+        .stdout("14846210710871074201\n");
+}
+
+#[test]
+fn safe_prime() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("prime")
+        .arg("--safe")
+        .arg("64")
+        .assert()
+        .success()
+        .stdout("16653779652547067183\n");
+}
+
+#[test]
+fn small_prime() {
+    utils::command::command()
+        .arg("-c")
+        .arg("3")
+        .arg("prime")
+        .arg("2")
+        .assert()
+        .success()
+        .stdout("3\n3\n3\n");
+}
+
+#[test]
+fn prime_with_insecure_rng() {
+    utils::command::command()
+        .arg("-r")
+        .arg("splitmix64")
+        .arg("prime")
+        .arg("32")
+        .assert()
+        .success()
+        .stderr("Warning: splitmix64 is not a cryptographically secure RNG\n");
+}
+
+#[test]
+fn prime_with_too_few_bits() {
+    utils::command::command()
+        .arg("prime")
+        .arg("1")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "invalid value '1' for '<BITS>': 1 is not in 2..=4294967295",
+        ));
+}
+
+#[test]
+fn bigint_with_zero_bits() {
+    utils::command::command()
+        .arg("bigint")
+        .arg("0")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "invalid value '0' for '<BITS>': 0 is not in 1..=4294967295",
+        ));
+}
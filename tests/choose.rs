@@ -0,0 +1,131 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod utils;
+
+use predicates::prelude::predicate;
+
+#[test]
+fn choose() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("-c")
+        .arg("10")
+        .arg("choose")
+        .arg("read:70")
+        .arg("write:25")
+        .arg("delete:5")
+        .assert()
+        .success()
+        .stdout("read\nread\nwrite\nread\ndelete\nread\nread\ndelete\nwrite\nread\n");
+}
+
+#[test]
+fn choose_from_file() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("-c")
+        .arg("10")
+        .arg("choose")
+        .arg("--file")
+        .arg("data/items.txt")
+        .assert()
+        .success()
+        .stdout("read\nread\nwrite\nread\ndelete\nread\nread\ndelete\nwrite\nread\n");
+}
+
+#[test]
+fn choose_with_zero_weight() {
+    utils::command::command()
+        .arg("-c")
+        .arg("100")
+        .arg("choose")
+        .arg("a:0")
+        .arg("b")
+        .assert()
+        .success()
+        .stdout(predicate::function(|output: &str| {
+            output.lines().all(|item| item == "b")
+        }));
+}
+
+#[test]
+fn choose_without_replacement() {
+    utils::command::command()
+        .arg("-c")
+        .arg("3")
+        .arg("-0")
+        .arg("choose")
+        .arg("--without-replacement")
+        .arg("a")
+        .arg("b:2")
+        .arg("c:0.5")
+        .assert()
+        .success()
+        .stdout(predicate::function(|output: &str| {
+            let mut items = output.split_terminator('\0').collect::<Vec<_>>();
+            items.sort_unstable();
+            items == ["a", "b", "c"]
+        }));
+}
+
+#[test]
+fn choose_too_many_without_replacement() {
+    utils::command::command()
+        .arg("-c")
+        .arg("3")
+        .arg("choose")
+        .arg("--without-replacement")
+        .arg("a")
+        .arg("b")
+        .arg("c:0")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "cannot choose 3 items without replacement from 2 items",
+        ));
+}
+
+#[test]
+fn choose_with_invalid_weight() {
+    utils::command::command()
+        .arg("choose")
+        .arg("a:-1")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "weight of `a` should be a non-negative finite number",
+        ));
+}
+
+#[test]
+fn choose_without_items() {
+    utils::command::command()
+        .arg("choose")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "the following required arguments were not provided",
+        ));
+}
+
+#[test]
+fn choose_with_items_and_file() {
+    utils::command::command()
+        .arg("choose")
+        .arg("--file")
+        .arg("data/items.txt")
+        .arg("a")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "the argument '--file <FILE>' cannot be used with '[ITEM]...'",
+        ));
+}
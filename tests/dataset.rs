@@ -0,0 +1,95 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod utils;
+
+use predicates::prelude::predicate;
+
+#[test]
+fn csv() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("dataset")
+        .arg("data/schema.toml")
+        .arg("-c")
+        .arg("3")
+        .assert()
+        .success()
+        .stdout(
+            "id,age,score,plan,code,created_at\n\
+             d17f36ca-3554-47f2-8b74-3534ef2c09ff,55,51.5,free,XWC-9336,2024-01-23T04:29:34Z\n\
+             4e629073-ac0b-4c9b-929a-535955770b2f,84,44.3,pro,IZH-7335,2024-02-18T07:22:35Z\n\
+             e618a9fe-a482-4a1e-8258-c3d73082bbfc,84,60.4,free,LQX-3953,2024-07-26T05:19:48Z\n",
+        );
+}
+
+#[test]
+fn csv_without_header() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("dataset")
+        .arg("--no-header")
+        .arg("data/schema.toml")
+        .assert()
+        .success()
+        .stdout(
+            "d17f36ca-3554-47f2-8b74-3534ef2c09ff,55,51.5,free,XWC-9336,2024-01-23T04:29:34Z\n",
+        );
+}
+
+#[test]
+fn ndjson() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("dataset")
+        .arg("-t")
+        .arg("ndjson")
+        .arg("data/schema.json")
+        .arg("-c")
+        .arg("2")
+        .assert()
+        .success()
+        .stdout("{\"id\":949,\"plan\":\"pro\"}\n{\"id\":322,\"plan\":\"free\"}\n");
+}
+
+#[test]
+fn nullable_column() {
+    let output = utils::command::command()
+        .arg("dataset")
+        .arg("--no-header")
+        .arg("data/schema.toml")
+        .arg("-c")
+        .arg("1000")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let nulls = stdout.lines().filter(|line| line.contains(",,")).count();
+    assert!((150..350).contains(&nulls));
+}
+
+#[test]
+fn invalid_schema() {
+    utils::command::command()
+        .arg("dataset")
+        .arg("data/wordlist.txt")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains("invalid schema data/wordlist.txt"));
+}
+
+#[test]
+fn schema_not_found() {
+    utils::command::command()
+        .arg("dataset")
+        .arg("non_existent.toml")
+        .assert()
+        .failure()
+        .code(66)
+        .stderr(predicate::str::contains("could not read non_existent.toml"));
+}
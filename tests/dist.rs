@@ -0,0 +1,215 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod utils;
+
+use predicates::prelude::predicate;
+
+#[test]
+fn uniform() {
+    let output = utils::command::command()
+        .arg("--dist")
+        .arg("uniform")
+        .arg("-c")
+        .arg("3")
+        .arg("-s")
+        .arg("256")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        output.stdout,
+        b"0.9485981589625342\n0.99623375739994\n0.3213698903820319\n"
+    );
+}
+
+#[test]
+fn uniform_is_in_range() {
+    let output = utils::command::command()
+        .arg("--dist")
+        .arg("uniform")
+        .arg("-c")
+        .arg("1000")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    for line in String::from_utf8(output.stdout).unwrap().lines() {
+        let n = line.parse::<f64>().unwrap();
+        assert!((0.0..1.0).contains(&n));
+    }
+}
+
+#[test]
+fn normal() {
+    let output = utils::command::command()
+        .arg("--dist")
+        .arg("normal:10,2")
+        .arg("-c")
+        .arg("3")
+        .arg("-s")
+        .arg("256")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        output.stdout,
+        b"11.726320813805142\n13.826979219357664\n9.134605908470217\n"
+    );
+}
+
+#[test]
+fn other_distributions() {
+    for (dist, expected) in [
+        (
+            "exp:2",
+            "0.3633937791964753 0.023938093991578216 0.8510226119034732 ",
+        ),
+        (
+            "gamma:2,1",
+            "3.047800165882465 1.1681419464275695 1.8640317111784392 ",
+        ),
+        (
+            "beta:2,5",
+            "0.20103143843009436 0.3026973032591326 0.30211545055924877 ",
+        ),
+        ("poisson:4", "5 4 4 "),
+        ("binomial:10,0.3", "5 7 2 "),
+    ] {
+        let output = utils::command::command()
+            .arg("--dist")
+            .arg(dist)
+            .arg("-c")
+            .arg("3")
+            .arg("-d")
+            .arg(" ")
+            .arg("-s")
+            .arg("256")
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        assert_eq!(output.stdout, expected.as_bytes());
+    }
+}
+
+#[test]
+fn precision() {
+    let output = utils::command::command()
+        .arg("--dist")
+        .arg("normal:10,2")
+        .arg("--precision")
+        .arg("2")
+        .arg("-c")
+        .arg("3")
+        .arg("-s")
+        .arg("256")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(output.stdout, b"11.73\n13.83\n9.13\n");
+}
+
+#[test]
+fn packed() {
+    {
+        let output = utils::command::command()
+            .arg("--dist")
+            .arg("uniform")
+            .arg("--packed")
+            .arg("f32")
+            .arg("-c")
+            .arg("2")
+            .arg("-s")
+            .arg("256")
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        assert_eq!(
+            output.stdout,
+            [0x54, 0xd7, 0x72, 0x3f, 0x2d, 0x09, 0x7f, 0x3f]
+        );
+    }
+    {
+        let output = utils::command::command()
+            .arg("--dist")
+            .arg("uniform")
+            .arg("--packed")
+            .arg("f64")
+            .arg("--endian")
+            .arg("big")
+            .arg("-s")
+            .arg("256")
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        assert_eq!(output.stdout, 0.948_598_158_962_534_2_f64.to_be_bytes());
+    }
+}
+
+#[test]
+fn endian_without_packed() {
+    utils::command::command()
+        .arg("--dist")
+        .arg("uniform")
+        .arg("--endian")
+        .arg("big")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "the following required arguments were not provided",
+        ));
+    utils::command::command()
+        .arg("--endian")
+        .arg("big")
+        .arg("4")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "the argument '--endian <ENDIAN>' cannot be used with '[BYTES]'",
+        ));
+}
+
+#[test]
+fn invalid_dist() {
+    utils::command::command()
+        .arg("--dist")
+        .arg("cauchy")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("unknown distribution `cauchy`"));
+    utils::command::command()
+        .arg("--dist")
+        .arg("gamma:1")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "wrong number of parameters for `gamma`",
+        ));
+    utils::command::command()
+        .arg("--dist")
+        .arg("exp:-1")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "invalid value 'exp:-1' for '--dist <DIST>'",
+        ));
+}
+
+#[test]
+fn precision_without_dist() {
+    utils::command::command()
+        .arg("--precision")
+        .arg("2")
+        .arg("32B")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "the argument '--precision <DIGITS>' cannot be used with '[BYTES]'",
+        ));
+}
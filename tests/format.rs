@@ -172,6 +172,402 @@ fn hex_check_no_line_wrapping() {
     assert_eq!(output.stdout, expected.as_bytes());
 }
 
+#[test]
+fn binary() {
+    let output = utils::command::command()
+        .arg("-f")
+        .arg("binary")
+        .arg("-s")
+        .arg("256")
+        .arg("4B")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let expected = "11010001011111110011011011001010";
+    assert_eq!(output.stdout, expected.as_bytes());
+}
+
+#[test]
+fn binary_with_long_output() {
+    let output = utils::command::command()
+        .arg("-f")
+        .arg("binary")
+        .arg("--bit-group")
+        .arg("byte")
+        .arg("16KiB")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(output.stdout.len(), (16384 * 9) - 1);
+    assert!(output.stdout.split(|&b| b == b' ').all(|g| g.len() == 8));
+}
+
+#[test]
+fn bit_order() {
+    let output = utils::command::command()
+        .arg("-f")
+        .arg("binary")
+        .arg("--bit-order")
+        .arg("lsb")
+        .arg("-s")
+        .arg("256")
+        .arg("4B")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let expected = "10001011111111100110110001010011";
+    assert_eq!(output.stdout, expected.as_bytes());
+}
+
+#[test]
+fn bit_group() {
+    {
+        let output = utils::command::command()
+            .arg("-f")
+            .arg("binary")
+            .arg("--bit-group")
+            .arg("nibble")
+            .arg("-s")
+            .arg("256")
+            .arg("2B")
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        assert_eq!(output.stdout, b"1101 0001 0111 1111");
+    }
+    {
+        let output = utils::command::command()
+            .arg("-f")
+            .arg("binary")
+            .arg("--bit-group")
+            .arg("byte")
+            .arg("-s")
+            .arg("256")
+            .arg("2B")
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        assert_eq!(output.stdout, b"11010001 01111111");
+    }
+}
+
+#[test]
+fn octal() {
+    let output = utils::command::command()
+        .arg("-f")
+        .arg("octal")
+        .arg("-s")
+        .arg("256")
+        .arg("8B")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let expected = "6427746662432524657710";
+    assert_eq!(output.stdout, expected.as_bytes());
+}
+
+#[test]
+fn octal_with_long_output() {
+    let output = utils::command::command()
+        .arg("-f")
+        .arg("octal")
+        .arg("16KiB")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    // 131072 bits are encoded into 43691 digits.
+    assert_eq!(output.stdout.len(), 43691);
+    assert!(output.stdout.iter().all(|b| (b'0'..=b'7').contains(b)));
+}
+
+#[test]
+fn proquint() {
+    utils::command::command()
+        .arg("-f")
+        .arg("proquint")
+        .arg("-s")
+        .arg("256")
+        .arg("9")
+        .assert()
+        .success()
+        .stdout("tajuz-girap-gijih-tizuf-hosab");
+}
+
+#[test]
+fn proquint_with_output_chars() {
+    utils::command::command()
+        .arg("-f")
+        .arg("proquint")
+        .arg("-s")
+        .arg("256")
+        .arg("--output-chars")
+        .arg("11")
+        .assert()
+        .success()
+        .stdout("tajuz-gimab");
+}
+
+#[test]
+fn pgp_words() {
+    utils::command::command()
+        .arg("-f")
+        .arg("pgp-words")
+        .arg("-s")
+        .arg("256")
+        .arg("-c")
+        .arg("2")
+        .arg("3")
+        .assert()
+        .success()
+        .stdout("stairway integrate christmas\nchopper equation stopwatch\n");
+}
+
+#[test]
+fn pgp_words_in_verbose_mode() {
+    utils::command::command()
+        .arg("-f")
+        .arg("pgp-words")
+        .arg("-v")
+        .arg("2")
+        .assert()
+        .success()
+        .stderr("Random bytes: 2\nEntropy: 16 bits\nMaximum output length: 21\n");
+}
+
+#[test]
+fn pgp_words_with_output_chars() {
+    utils::command::command()
+        .arg("-f")
+        .arg("pgp-words")
+        .arg("--output-chars")
+        .arg("16")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "--output-chars cannot be used with the pgp-words format",
+        ));
+}
+
+#[test]
+fn c() {
+    let output = utils::command::command()
+        .arg("-f")
+        .arg("c")
+        .arg("-s")
+        .arg("256")
+        .arg("16B")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let expected = "const uint8_t random_bytes[16] = {
+    0xd1, 0x7f, 0x36, 0xca, 0x35, 0x54, 0xd7, 0xf2, 0x4b, 0x74, 0x35, 0x34,
+    0xef, 0x2c, 0x09, 0xff,
+};
+";
+    assert_eq!(output.stdout, expected.as_bytes());
+}
+
+#[test]
+fn c_without_bytes() {
+    utils::command::command()
+        .arg("-f")
+        .arg("c")
+        .arg("0")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "the c format requires at least 1 byte",
+        ));
+}
+
+#[test]
+fn go() {
+    let output = utils::command::command()
+        .arg("-f")
+        .arg("go")
+        .arg("-s")
+        .arg("256")
+        .arg("16B")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let expected = "var randomBytes = []byte{
+\t0xd1, 0x7f, 0x36, 0xca, 0x35, 0x54, 0xd7, 0xf2, 0x4b, 0x74, 0x35, 0x34,
+\t0xef, 0x2c, 0x09, 0xff,
+}
+";
+    assert_eq!(output.stdout, expected.as_bytes());
+}
+
+#[test]
+fn javascript() {
+    let expected = "const randomBytes = new Uint8Array([
+  0xd1, 0x7f, 0x36, 0xca, 0x35, 0x54, 0xd7, 0xf2, 0x4b, 0x74, 0x35, 0x34,
+  0xef, 0x2c, 0x09, 0xff,
+]);
+";
+    {
+        let output = utils::command::command()
+            .arg("-f")
+            .arg("javascript")
+            .arg("-s")
+            .arg("256")
+            .arg("16B")
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        assert_eq!(output.stdout, expected.as_bytes());
+    }
+    {
+        let output = utils::command::command()
+            .arg("-f")
+            .arg("js")
+            .arg("-s")
+            .arg("256")
+            .arg("16B")
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        assert_eq!(output.stdout, expected.as_bytes());
+    }
+}
+
+#[test]
+fn python() {
+    let output = utils::command::command()
+        .arg("-f")
+        .arg("python")
+        .arg("-s")
+        .arg("256")
+        .arg("16B")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let expected = r#"random_bytes = (
+    b"\xd1\x7f\x36\xca\x35\x54\xd7\xf2\x4b\x74\x35\x34"
+    b"\xef\x2c\x09\xff"
+)
+"#;
+    assert_eq!(output.stdout, expected.as_bytes());
+}
+
+#[test]
+fn rust() {
+    let output = utils::command::command()
+        .arg("-f")
+        .arg("rust")
+        .arg("-s")
+        .arg("256")
+        .arg("16B")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let expected = "const RANDOM_BYTES: [u8; 16] = [
+    0xd1, 0x7f, 0x36, 0xca, 0x35, 0x54, 0xd7, 0xf2, 0x4b, 0x74, 0x35, 0x34,
+    0xef, 0x2c, 0x09, 0xff,
+];
+";
+    assert_eq!(output.stdout, expected.as_bytes());
+}
+
+#[test]
+fn rust_with_long_output() {
+    let output = utils::command::command()
+        .arg("-f")
+        .arg("rust")
+        .arg("-s")
+        .arg("256")
+        .arg("16KiB")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let output = String::from_utf8(output.stdout).unwrap();
+    assert!(output.starts_with("const RANDOM_BYTES: [u8; 16384] = [\n"));
+    assert!(output.ends_with("];\n"));
+    // The header, 1366 lines of items and the footer.
+    assert_eq!(output.lines().count(), 1368);
+}
+
+#[test]
+fn rust_with_zero_length() {
+    let output = utils::command::command()
+        .arg("-f")
+        .arg("rust")
+        .arg("0")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(output.stdout, b"const RANDOM_BYTES: [u8; 0] = [\n];\n");
+}
+
+#[test]
+fn name() {
+    let output = utils::command::command()
+        .arg("-f")
+        .arg("rust")
+        .arg("--name")
+        .arg("KEY")
+        .arg("-s")
+        .arg("256")
+        .arg("4B")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let expected = "const KEY: [u8; 4] = [\n    0xd1, 0x7f, 0x36, 0xca,\n];\n";
+    assert_eq!(output.stdout, expected.as_bytes());
+}
+
+#[test]
+fn invalid_name() {
+    utils::command::command()
+        .arg("-f")
+        .arg("rust")
+        .arg("--name")
+        .arg("1KEY")
+        .arg("4B")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "invalid value '1KEY' for '--name <NAME>'",
+        ));
+}
+
+#[test]
+fn items_per_line() {
+    let output = utils::command::command()
+        .arg("-f")
+        .arg("go")
+        .arg("--items-per-line")
+        .arg("2")
+        .arg("-s")
+        .arg("256")
+        .arg("4B")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let expected = "var randomBytes = []byte{\n\t0xd1, 0x7f,\n\t0x36, 0xca,\n}\n";
+    assert_eq!(output.stdout, expected.as_bytes());
+}
+
+#[test]
+fn items_per_line_is_zero() {
+    utils::command::command()
+        .arg("-f")
+        .arg("c")
+        .arg("--items-per-line")
+        .arg("0")
+        .arg("4B")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "invalid value '0' for '--items-per-line <NUMBER>'",
+        ));
+}
+
 #[test]
 fn invalid_format() {
     utils::command::command()
@@ -224,3 +620,297 @@ fn hex_with_too_long_output() {
         .code(1)
         .stderr(predicate::str::contains("output is too long"));
 }
+
+#[cfg(feature = "bip39")]
+#[test]
+fn bip39() {
+    utils::command::command()
+        .arg("-f")
+        .arg("bip39")
+        .arg("-s")
+        .arg("256")
+        .arg("16")
+        .assert()
+        .success()
+        .stdout("sphere whale raw height estate weird column mammal crunch just act wrap");
+}
+
+#[cfg(feature = "bip39")]
+#[test]
+fn bip39_with_bits() {
+    utils::command::command()
+        .arg("-f")
+        .arg("bip39")
+        .arg("-s")
+        .arg("256")
+        .arg("--bits")
+        .arg("160")
+        .assert()
+        .success()
+        .stdout(
+            "sphere whale raw height estate weird column mammal crunch just act youth amount \
+             bamboo icon",
+        );
+}
+
+#[cfg(feature = "bip39")]
+#[test]
+fn bip39_with_count() {
+    utils::command::command()
+        .arg("-f")
+        .arg("bip39")
+        .arg("-s")
+        .arg("256")
+        .arg("-c")
+        .arg("3")
+        .arg("32")
+        .assert()
+        .success()
+        .stdout(predicate::function(|output: &str| {
+            output.lines().count() == 3 && output.lines().all(|line| line.split(' ').count() == 24)
+        }));
+}
+
+#[cfg(feature = "bip39")]
+#[test]
+fn bip39_with_invalid_length() {
+    utils::command::command()
+        .arg("-f")
+        .arg("bip39")
+        .arg("15")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "the bip39 format requires 16, 20, 24, 28 or 32 bytes, but 15 bytes were specified",
+        ));
+}
+
+#[cfg(feature = "bip39")]
+#[test]
+fn bip39_with_output_chars() {
+    utils::command::command()
+        .arg("-f")
+        .arg("bip39")
+        .arg("--output-chars")
+        .arg("100")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "--output-chars cannot be used with the bip39 format",
+        ));
+}
+
+#[cfg(feature = "bip39")]
+#[test]
+fn bip39_with_multibase() {
+    utils::command::command()
+        .arg("-f")
+        .arg("bip39")
+        .arg("--multibase")
+        .arg("16")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "--multibase cannot be used with the bip39 format",
+        ));
+}
+
+#[cfg(feature = "bech32")]
+#[test]
+fn bech32() {
+    utils::command::command()
+        .arg("-f")
+        .arg("bech32")
+        .arg("--hrp")
+        .arg("npub")
+        .arg("-s")
+        .arg("256")
+        .arg("32")
+        .assert()
+        .success()
+        .stdout("npub169lndj342ntlyjm5x56w7tqfl7cgqfquz9xy25h794lfzxq3ap6quqw39v");
+}
+
+#[cfg(feature = "bech32")]
+#[test]
+fn bech32m() {
+    utils::command::command()
+        .arg("-f")
+        .arg("bech32m")
+        .arg("--hrp")
+        .arg("test")
+        .arg("-s")
+        .arg("256")
+        .arg("16")
+        .assert()
+        .success()
+        .stdout("test169lndj342ntlyjm5x56w7tqflu8a3g8g");
+}
+
+#[cfg(feature = "bech32")]
+#[test]
+fn bech32_with_output_chars() {
+    utils::command::command()
+        .arg("-f")
+        .arg("bech32")
+        .arg("--hrp")
+        .arg("a")
+        .arg("-s")
+        .arg("256")
+        .arg("--output-chars")
+        .arg("16")
+        .assert()
+        .success()
+        .stdout("a169lndj346e92qc");
+}
+
+#[cfg(feature = "bech32")]
+#[test]
+fn bech32_without_hrp() {
+    utils::command::command()
+        .arg("-f")
+        .arg("bech32")
+        .arg("32")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("--hrp <HRP>"));
+}
+
+#[cfg(feature = "bech32")]
+#[test]
+fn bech32_with_invalid_hrp() {
+    utils::command::command()
+        .arg("-f")
+        .arg("bech32")
+        .arg("--hrp")
+        .arg("")
+        .arg("32")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "invalid value '' for '--hrp <HRP>'",
+        ));
+}
+
+#[cfg(feature = "bech32")]
+#[test]
+fn bech32_with_too_long_output() {
+    utils::command::command()
+        .arg("-f")
+        .arg("bech32")
+        .arg("--hrp")
+        .arg("a")
+        .arg("52")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "Bech32 string should be at most 90 characters, but 52 bytes are encoded in 92 \
+             characters",
+        ));
+    utils::command::command()
+        .arg("-f")
+        .arg("bech32")
+        .arg("--hrp")
+        .arg("a")
+        .arg("51")
+        .assert()
+        .success()
+        .stdout(predicate::function(|output: &str| output.len() == 90));
+}
+
+#[cfg(feature = "bech32")]
+#[test]
+fn hrp_with_other_format() {
+    utils::command::command()
+        .arg("-f")
+        .arg("hex")
+        .arg("--hrp")
+        .arg("abc")
+        .arg("4")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "the argument '--hrp <HRP>' can only be used with the bech32 and bech32m formats",
+        ));
+}
+
+#[cfg(feature = "hex")]
+#[test]
+fn multibase() {
+    utils::command::command()
+        .arg("-f")
+        .arg("hex")
+        .arg("--multibase")
+        .arg("-s")
+        .arg("256")
+        .arg("-c")
+        .arg("2")
+        .arg("4")
+        .assert()
+        .success()
+        .stdout("fd17f36ca\nf3554d7f2\n");
+}
+
+#[test]
+fn multibase_with_binary() {
+    utils::command::command()
+        .arg("-f")
+        .arg("binary")
+        .arg("--multibase")
+        .arg("-s")
+        .arg("256")
+        .arg("1")
+        .assert()
+        .success()
+        .stdout("011010001");
+    utils::command::command()
+        .arg("-f")
+        .arg("binary")
+        .arg("--bit-group")
+        .arg("byte")
+        .arg("--multibase")
+        .arg("1")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "multibase requires `--bit-order msb` and `--bit-group none`",
+        ));
+}
+
+#[test]
+fn multibase_with_output_chars() {
+    utils::command::command()
+        .arg("-f")
+        .arg("octal")
+        .arg("--multibase")
+        .arg("--output-chars")
+        .arg("4")
+        .assert()
+        .success()
+        .stdout(predicate::function(|output: &[u8]| {
+            output.len() == 4 && output[0] == b'7'
+        }));
+}
+
+#[test]
+fn multibase_without_code() {
+    utils::command::command()
+        .arg("-f")
+        .arg("rust")
+        .arg("--multibase")
+        .arg("4")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "the rust format has no multibase code",
+        ));
+}
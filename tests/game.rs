@@ -0,0 +1,155 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod utils;
+
+use predicates::prelude::predicate;
+
+#[test]
+fn roll() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("roll")
+        .arg("4d6kh3")
+        .arg("1d20+5")
+        .assert()
+        .success()
+        .stdout("4d6kh3: [6 6 (2) 3] = 15\n1d20+5: [11] + 5 = 16\n");
+}
+
+#[test]
+fn roll_as_json() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("roll")
+        .arg("-t")
+        .arg("json")
+        .arg("1d6!+2")
+        .assert()
+        .success()
+        .stdout(concat!(
+            r#"{"expression":"1d6!+2","terms":[{"type":"dice","notation":"1d6!","negative":false,"#,
+            r#""rolls":[{"value":14,"rolls":[6,6,2],"kept":true}],"total":14},"#,
+            r#"{"type":"constant","value":2}],"total":16}"#,
+            "\n"
+        ));
+}
+
+#[test]
+fn roll_exploding_dice_with_keep() {
+    utils::command::command()
+        .arg("-s")
+        .arg("1")
+        .arg("roll")
+        .arg("2d6!kh1")
+        .assert()
+        .success()
+        .stdout("2d6!kh1: [6!5 (3)] = 11\n");
+}
+
+#[test]
+fn roll_with_count() {
+    utils::command::command()
+        .arg("-c")
+        .arg("100")
+        .arg("roll")
+        .arg("2d6")
+        .assert()
+        .success()
+        .stdout(predicate::function(|output: &str| {
+            output.lines().count() == 100
+                && output.lines().all(|line| {
+                    let total = line.rsplit_once(" = ").unwrap().1.parse::<u8>().unwrap();
+                    (2..=12).contains(&total)
+                })
+        }));
+}
+
+#[test]
+fn roll_with_invalid_expression() {
+    utils::command::command()
+        .arg("roll")
+        .arg("4d6kx")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "invalid value '4d6kx' for '<EXPRESSION>...': unexpected character `x`",
+        ));
+}
+
+#[test]
+fn deal() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("deal")
+        .arg("-H")
+        .arg("2")
+        .arg("5")
+        .assert()
+        .success()
+        .stdout("Js Ks 6d Kd 4h\nAd 5h 9s 7s 6c\n");
+}
+
+#[test]
+fn deal_as_json() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("deal")
+        .arg("-t")
+        .arg("json")
+        .arg("-H")
+        .arg("2")
+        .arg("3")
+        .assert()
+        .success()
+        .stdout("[[\"Js\",\"Ks\",\"6d\"],[\"Kd\",\"4h\",\"Ad\"]]\n");
+}
+
+#[test]
+fn deal_too_many_cards() {
+    utils::command::command()
+        .arg("deal")
+        .arg("-H")
+        .arg("11")
+        .arg("5")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "cannot deal 55 cards from a deck of 52 cards",
+        ));
+}
+
+#[test]
+fn deal_to_too_many_hands() {
+    utils::command::command()
+        .arg("deal")
+        .arg("-H")
+        .arg("18446744073709551615")
+        .arg("0")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "cannot deal to more than 52 hands",
+        ));
+}
+
+#[test]
+fn flip() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("-c")
+        .arg("4")
+        .arg("flip")
+        .assert()
+        .success()
+        .stdout("tails\ntails\nheads\nheads\n");
+}
@@ -113,3 +113,240 @@ fn validate_length_with_nan() {
             "the character 'n' is not a number",
         ));
 }
+
+#[test]
+fn generate_records() {
+    let output = utils::command::command()
+        .arg("-c")
+        .arg("4")
+        .arg("32")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(output.stdout.len(), (32 + 1) * 4);
+    assert!(output.stdout.chunks(33).all(|record| record[32] == b'\n'));
+}
+
+#[cfg(feature = "base64")]
+#[test]
+fn generate_records_with_padding() {
+    utils::command::command()
+        .arg("-f")
+        .arg("base64url")
+        .arg("-c")
+        .arg("3")
+        .arg("-s")
+        .arg("256")
+        .arg("4")
+        .assert()
+        .success()
+        .stdout("0X82yg==\nNVTX8g==\nS3Q1NA==\n");
+}
+
+#[cfg(feature = "hex")]
+#[test]
+fn generate_records_with_delimiter() {
+    utils::command::command()
+        .arg("-f")
+        .arg("hex")
+        .arg("-c")
+        .arg("2")
+        .arg("-d")
+        .arg(",")
+        .arg("-s")
+        .arg("256")
+        .arg("4")
+        .assert()
+        .success()
+        .stdout("d17f36ca,3554d7f2,");
+    utils::command::command()
+        .arg("-f")
+        .arg("hex")
+        .arg("-c")
+        .arg("2")
+        .arg("-0")
+        .arg("-s")
+        .arg("256")
+        .arg("4")
+        .assert()
+        .success()
+        .stdout("d17f36ca\x003554d7f2\x00");
+}
+
+#[test]
+fn generate_records_with_octal() {
+    // Each record is padded separately.
+    utils::command::command()
+        .arg("-f")
+        .arg("octal")
+        .arg("-c")
+        .arg("2")
+        .arg("-s")
+        .arg("256")
+        .arg("1")
+        .assert()
+        .success()
+        .stdout("642\n152\n");
+}
+
+#[test]
+fn delimiter_with_null() {
+    utils::command::command()
+        .arg("-d")
+        .arg(",")
+        .arg("-0")
+        .arg("32")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "the argument '--delimiter <STRING>' cannot be used with '--null'",
+        ));
+}
+
+#[test]
+fn generate_unique_records() {
+    let output = utils::command::command()
+        .arg("-c")
+        .arg("256")
+        .arg("--unique")
+        .arg("1")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        output.stderr,
+        b"Collision probability without --unique: 1.00e0\n"
+    );
+    let mut records = output.stdout.chunks(2).map(|r| r[0]).collect::<Vec<_>>();
+    records.sort_unstable();
+    assert!(records.iter().copied().eq(u8::MIN..=u8::MAX));
+}
+
+#[test]
+fn generate_unique_long_records() {
+    let output = utils::command::command()
+        .arg("-c")
+        .arg("1000")
+        .arg("--unique")
+        .arg("20")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let mut records = output.stdout.chunks(21).collect::<Vec<_>>();
+    assert_eq!(records.len(), 1000);
+    records.sort_unstable();
+    records.dedup();
+    assert_eq!(records.len(), 1000);
+}
+
+#[test]
+fn generate_too_many_unique_records() {
+    utils::command::command()
+        .arg("-c")
+        .arg("257")
+        .arg("--unique")
+        .arg("1")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "cannot generate 257 unique records of 1 bytes",
+        ));
+}
+
+#[test]
+fn generate_unique_with_subcommand() {
+    utils::command::command()
+        .arg("-c")
+        .arg("2")
+        .arg("--unique")
+        .arg("uuid")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "--unique cannot be used with subcommands other than net",
+        ));
+}
+
+#[test]
+fn generate_with_bits() {
+    let output = utils::command::command()
+        .arg("--bits")
+        .arg("129")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(output.stdout.len(), 17);
+}
+
+#[cfg(feature = "base64")]
+#[test]
+fn generate_with_bits_in_verbose_mode() {
+    utils::command::command()
+        .arg("-f")
+        .arg("base64")
+        .arg("--bits")
+        .arg("128")
+        .arg("-v")
+        .arg("-s")
+        .arg("256")
+        .assert()
+        .success()
+        .stdout("0X82yjVU1/JLdDU07ywJ/w==")
+        .stderr("Random bytes: 16\nEntropy: 128 bits\nOutput length: 24\n");
+}
+
+#[test]
+fn generate_with_output_chars() {
+    let output = utils::command::command()
+        .arg("-f")
+        .arg("octal")
+        .arg("--output-chars")
+        .arg("43")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(output.stdout.len(), 43);
+    let output = utils::command::command()
+        .arg("-f")
+        .arg("rust")
+        .arg("--output-chars")
+        .arg("100")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(output.stdout.len(), 100);
+}
+
+#[cfg(feature = "base64")]
+#[test]
+fn generate_with_unreachable_output_chars() {
+    utils::command::command()
+        .arg("-f")
+        .arg("base64")
+        .arg("--output-chars")
+        .arg("22")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "no number of bytes is encoded in exactly 22 characters in base64; the nearest \
+             lengths are 20 and 24",
+        ));
+}
+
+#[test]
+fn bits_with_length() {
+    utils::command::command()
+        .arg("--bits")
+        .arg("128")
+        .arg("16")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "the argument '--bits <BITS>' cannot be used with '[BYTES]'",
+        ));
+}
@@ -0,0 +1,125 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod utils;
+
+use predicates::prelude::predicate;
+
+#[test]
+fn ulid() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("ulid")
+        .arg("-c")
+        .arg("2")
+        .assert()
+        .success()
+        .stdout("01JGFJJZ00FZ8ZY29CXWT3AX2B\n01JGFJJZ00G2R79T0H328QWBFY\n");
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("--clock")
+        .arg("1469922850259")
+        .arg("ulid")
+        .assert()
+        .success()
+        .stdout("01ARZ3NDEKFZ8ZY29CXWT3AX2B\n");
+}
+
+#[test]
+fn monotonic_ulid() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("ulid")
+        .arg("--monotonic")
+        .arg("-c")
+        .arg("3")
+        .assert()
+        .success()
+        .stdout(
+            "01JGFJJZ00FZ8ZY29CXWT3AX2B\n01JGFJJZ00FZ8ZY29CXWT3AX2C\n01JGFJJZ00FZ8ZY29CXWT3AX2D\n",
+        );
+}
+
+#[test]
+fn ksuid() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("ksuid")
+        .arg("-c")
+        .arg("2")
+        .assert()
+        .success()
+        .stdout("2r0IWyVb1BMV4XszrvvnVe428xb\n2r0IWxVKh9E1ImG2yKRFhntmfBM\n");
+}
+
+#[test]
+fn ksuid_with_too_early_timestamp() {
+    utils::command::command()
+        .arg("--clock")
+        .arg("0")
+        .arg("ksuid")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "timestamp 0 is out of the range of KSUID timestamps",
+        ));
+}
+
+#[test]
+fn nanoid() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("nanoid")
+        .arg("-c")
+        .arg("2")
+        .assert()
+        .success()
+        .stdout("WZirw9wUQ4eZhkG1A9vOc\nJpjYfDKjnz65ZyNPCfqCS\n");
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("nanoid")
+        .arg("--alphabet")
+        .arg("0123456789abcdef")
+        .arg("--size")
+        .arg("10")
+        .assert()
+        .success()
+        .stdout("ff57828ed1\n");
+}
+
+#[test]
+fn nanoid_with_invalid_alphabet() {
+    utils::command::command()
+        .arg("nanoid")
+        .arg("--alphabet")
+        .arg("aa")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "alphabet should not contain duplicate characters",
+        ));
+}
+
+#[test]
+fn nanoid_with_bits() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("nanoid")
+        .arg("--bits")
+        .arg("128")
+        .arg("-v")
+        .assert()
+        .success()
+        .stdout("WZirw9wUQ4eZhkG1A9vOcJ\n")
+        .stderr("Size: 22\nEntropy: 132.00 bits\n");
+}
@@ -0,0 +1,209 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod utils;
+
+use predicates::prelude::predicate;
+
+#[test]
+fn int() {
+    let output = utils::command::command()
+        .arg("--int")
+        .arg("1..6")
+        .arg("-c")
+        .arg("8")
+        .arg("-s")
+        .arg("256")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(output.stdout, b"6\n6\n2\n3\n4\n2\n4\n6\n");
+}
+
+#[test]
+fn int_is_in_range() {
+    let output = utils::command::command()
+        .arg("--int")
+        .arg("1..=6")
+        .arg("--count")
+        .arg("6000")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let mut counts = [usize::MIN; 6];
+    for line in String::from_utf8(output.stdout).unwrap().lines() {
+        let n = line.parse::<usize>().unwrap();
+        assert!((1..=6).contains(&n));
+        counts[n - 1] += 1;
+    }
+    assert!(counts.iter().all(|&c| c > 0));
+}
+
+#[test]
+fn int_with_signed_range() {
+    let output = utils::command::command()
+        .arg("--int=-100..=100")
+        .arg("-c")
+        .arg("4")
+        .arg("-s")
+        .arg("256")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(output.stdout, b"90\n100\n-36\n-9\n");
+}
+
+#[test]
+fn int_with_128_bit_range() {
+    {
+        let output = utils::command::command()
+            .arg("--int")
+            .arg("0..340282366920938463463374607431768211455")
+            .arg("-c")
+            .arg("2")
+            .arg("-s")
+            .arg("256")
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        assert_eq!(
+            output.stdout,
+            b"322791226788615806771641868193988179019\n\
+              109356506956320354276391297033315429886\n"
+        );
+    }
+    {
+        let output = utils::command::command()
+            .arg("--int")
+            .arg(
+                "-170141183460469231731687303715884105728..\
+                 170141183460469231731687303715884105727",
+            )
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+    }
+}
+
+#[test]
+fn int_with_single_value() {
+    let output = utils::command::command()
+        .arg("--int")
+        .arg("7..7")
+        .arg("-c")
+        .arg("3")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(output.stdout, b"7\n7\n7\n");
+}
+
+#[test]
+fn count_is_zero() {
+    let output = utils::command::command()
+        .arg("--int")
+        .arg("1..6")
+        .arg("-c")
+        .arg("0")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+}
+
+#[test]
+fn delimiter() {
+    let output = utils::command::command()
+        .arg("--int")
+        .arg("1..6")
+        .arg("-c")
+        .arg("4")
+        .arg("-d")
+        .arg(",")
+        .arg("-s")
+        .arg("256")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(output.stdout, b"6,6,2,3,");
+}
+
+#[test]
+fn invalid_int_range() {
+    utils::command::command()
+        .arg("--int")
+        .arg("6..1")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "minimum value should be less than or equal to maximum value",
+        ));
+    utils::command::command()
+        .arg("--int")
+        .arg("1-6")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "range should be in the form `MIN..MAX`",
+        ));
+    utils::command::command()
+        .arg("--int")
+        .arg("1..340282366920938463463374607431768211456")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("invalid maximum value"));
+}
+
+#[test]
+fn int_with_length() {
+    utils::command::command()
+        .arg("--int")
+        .arg("1..6")
+        .arg("32B")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "the argument '--int <MIN..MAX>' cannot be used with '[BYTES]'",
+        ));
+}
+
+#[test]
+fn unique_int() {
+    let output = utils::command::command()
+        .arg("--int")
+        .arg("1..=6")
+        .arg("-c")
+        .arg("6")
+        .arg("--unique")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        output.stderr,
+        b"Collision probability without --unique: 9.18e-1\n"
+    );
+    let mut values = String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(|line| line.parse::<u8>().unwrap())
+        .collect::<Vec<_>>();
+    values.sort_unstable();
+    assert_eq!(values, [1, 2, 3, 4, 5, 6]);
+    utils::command::command()
+        .arg("--int")
+        .arg("1..=6")
+        .arg("-c")
+        .arg("7")
+        .arg("--unique")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "cannot generate 7 unique integers in the range",
+        ));
+}
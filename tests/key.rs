@@ -0,0 +1,138 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "base64")]
+
+mod utils;
+
+use predicates::prelude::predicate;
+
+#[test]
+fn jwk() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("key")
+        .arg("jwk")
+        .assert()
+        .success()
+        .stdout("{\"kty\":\"oct\",\"k\":\"0X82yjVU1_JLdDU07ywJ_7CAJBwRTEVS_i1-kRgR6HQ\"}\n");
+}
+
+#[test]
+fn jwk_with_alg_and_kid() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("key")
+        .arg("--alg")
+        .arg("a128gcm")
+        .arg("--kid")
+        .arg("key-1")
+        .arg("jwk")
+        .assert()
+        .success()
+        .stdout(
+            "{\"kty\":\"oct\",\"alg\":\"A128GCM\",\"kid\":\"key-1\",\"k\":\"0X82yjVU1_JLdDU07ywJ_w\"}\n",
+        );
+}
+
+#[test]
+fn jwk_with_length() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("key")
+        .arg("-l")
+        .arg("16")
+        .arg("jwk")
+        .assert()
+        .success()
+        .stdout("{\"kty\":\"oct\",\"k\":\"0X82yjVU1_JLdDU07ywJ_w\"}\n");
+}
+
+#[test]
+fn jwk_with_too_short_length() {
+    utils::command::command()
+        .arg("key")
+        .arg("--alg")
+        .arg("HS512")
+        .arg("-l")
+        .arg("32")
+        .arg("jwk")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "HS512 requires a key of at least 64 bytes, but 32 bytes were specified",
+        ));
+}
+
+#[test]
+fn wireguard() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("-c")
+        .arg("2")
+        .arg("key")
+        .arg("wireguard")
+        .assert()
+        .success()
+        .stdout(
+            "0H82yjVU1/JLdDU07ywJ/7CAJBwRTEVS/i1+kRgR6HQ=\nKG/YxckDbohSAZq07nKcLfgmY2Y/\
+             dCWIxOg9nqyRJ2k=\n",
+        );
+}
+
+#[cfg(feature = "bech32")]
+#[test]
+fn age() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("key")
+        .arg("age")
+        .assert()
+        .success()
+        .stdout("AGE-SECRET-KEY-169LNDJ342NTLYJM5X56W7TQFL7CGQFQUZ9XY25H794LFZXQ3AP6QEHAVSY\n");
+}
+
+#[test]
+fn key_with_jwk_options() {
+    utils::command::command()
+        .arg("key")
+        .arg("--kid")
+        .arg("key-1")
+        .arg("wireguard")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "--alg, --kid and --length can only be used with the jwk type",
+        ));
+}
+
+#[test]
+fn key_with_insecure_rng() {
+    utils::command::command()
+        .arg("-r")
+        .arg("splitmix64")
+        .arg("key")
+        .arg("jwk")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "splitmix64 is not a cryptographically secure RNG",
+        ));
+    utils::command::command()
+        .arg("-r")
+        .arg("splitmix64")
+        .arg("key")
+        .arg("--allow-insecure")
+        .arg("jwk")
+        .assert()
+        .success();
+}
@@ -0,0 +1,190 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod utils;
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use predicates::prelude::predicate;
+
+#[test]
+fn mac() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("-c")
+        .arg("3")
+        .arg("net")
+        .arg("mac")
+        .assert()
+        .success()
+        .stdout("f2:b5:d5:0d:72:8d\nfe:c2:4b:3b:cd:0d\n52:91:53:04:47:09\n");
+}
+
+#[test]
+fn mac_is_locally_administered_unicast() {
+    utils::command::command()
+        .arg("-c")
+        .arg("100")
+        .arg("net")
+        .arg("mac")
+        .assert()
+        .success()
+        .stdout(predicate::function(|output: &str| {
+            output.lines().all(|line| {
+                let first = u8::from_str_radix(&line[..2], 16).unwrap();
+                line.len() == 17 && first & 0b11 == 0b10
+            })
+        }));
+}
+
+#[test]
+fn ipv4() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("-c")
+        .arg("3")
+        .arg("net")
+        .arg("ip")
+        .arg("10.0.0.0/8")
+        .assert()
+        .success()
+        .stdout("10.242.215.84\n10.255.9.44\n10.82.69.76\n");
+}
+
+#[test]
+fn ipv6() {
+    utils::command::command()
+        .arg("-c")
+        .arg("100")
+        .arg("net")
+        .arg("ip")
+        .arg("2001:db8::/32")
+        .assert()
+        .success()
+        .stdout(predicate::function(|output: &str| {
+            output.lines().all(|line| {
+                let addr = line.parse::<Ipv6Addr>().unwrap();
+                addr.segments()[..2] == [0x2001, 0x0db8]
+            })
+        }));
+}
+
+#[test]
+fn ip_with_hosts_only() {
+    utils::command::command()
+        .arg("-c")
+        .arg("100")
+        .arg("net")
+        .arg("ip")
+        .arg("--hosts-only")
+        .arg("192.168.0.0/30")
+        .assert()
+        .success()
+        .stdout(predicate::function(|output: &str| {
+            output.lines().all(|line| {
+                let addr = line.parse::<Ipv4Addr>().unwrap();
+                [Ipv4Addr::new(192, 168, 0, 1), Ipv4Addr::new(192, 168, 0, 2)].contains(&addr)
+            })
+        }));
+}
+
+#[test]
+fn unique_ip() {
+    utils::command::command()
+        .arg("-c")
+        .arg("2")
+        .arg("--unique")
+        .arg("net")
+        .arg("ip")
+        .arg("--hosts-only")
+        .arg("192.168.0.0/30")
+        .assert()
+        .success()
+        .stdout(predicate::function(|output: &str| {
+            let mut lines = output.lines().collect::<Vec<_>>();
+            lines.sort_unstable();
+            lines == ["192.168.0.1", "192.168.0.2"]
+        }));
+    utils::command::command()
+        .arg("-c")
+        .arg("3")
+        .arg("--unique")
+        .arg("net")
+        .arg("ip")
+        .arg("--hosts-only")
+        .arg("192.168.0.0/30")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "cannot generate 3 unique IP addresses in the range",
+        ));
+}
+
+#[test]
+fn ip_with_host_bits() {
+    utils::command::command()
+        .arg("net")
+        .arg("ip")
+        .arg("10.0.0.1/8")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "`10.0.0.1` has host bits set; the network address is `10.0.0.0/8`",
+        ));
+}
+
+#[test]
+fn port() {
+    utils::command::command()
+        .arg("-c")
+        .arg("100")
+        .arg("net")
+        .arg("port")
+        .assert()
+        .success()
+        .stdout(predicate::function(|output: &str| {
+            output
+                .lines()
+                .all(|line| line.parse::<u16>().unwrap() >= 49152)
+        }));
+}
+
+#[test]
+fn unique_port() {
+    utils::command::command()
+        .arg("-c")
+        .arg("11")
+        .arg("--unique")
+        .arg("net")
+        .arg("port")
+        .arg("80..=90")
+        .assert()
+        .success()
+        .stdout(predicate::function(|output: &str| {
+            let mut ports = output
+                .lines()
+                .map(|line| line.parse::<u16>().unwrap())
+                .collect::<Vec<_>>();
+            ports.sort_unstable();
+            ports == (80..=90).collect::<Vec<_>>()
+        }));
+}
+
+#[test]
+fn port_out_of_range() {
+    utils::command::command()
+        .arg("net")
+        .arg("port")
+        .arg("0..65536")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "port numbers should be between 0 and 65535",
+        ));
+}
@@ -0,0 +1,104 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod utils;
+
+use predicates::prelude::predicate;
+
+#[test]
+fn passphrase() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("passphrase")
+        .arg("-c")
+        .arg("2")
+        .assert()
+        .success()
+        .stdout(
+            "unwrapped yippee fancied kerosene nimble crease\nneuter undrilled tables bulge \
+             dropkick yelling\n",
+        )
+        .stderr("Entropy: 77.55 bits\n");
+}
+
+#[test]
+fn passphrase_with_options() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("passphrase")
+        .arg("-w")
+        .arg("4")
+        .arg("--case")
+        .arg("upper")
+        .arg("--separator")
+        .arg(".")
+        .arg("--digit")
+        .assert()
+        .success()
+        .stdout("UNWRAPPED5.YIPPEE.FANCIED.KEROSENE\n")
+        .stderr("Entropy: 57.02 bits\n");
+}
+
+#[test]
+fn passphrase_with_entropy() {
+    let output = utils::command::command()
+        .arg("passphrase")
+        .arg("--list")
+        .arg("eff-short")
+        .arg("--entropy")
+        .arg("64")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    // log2(1296) is about 10.34, so 7 words are needed.
+    assert_eq!(output.stderr, b"Entropy: 72.38 bits\n");
+    let passphrase = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(passphrase.split(' ').count(), 7);
+}
+
+#[test]
+fn passphrase_with_wordlist() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("passphrase")
+        .arg("--wordlist")
+        .arg("data/wordlist.txt")
+        .arg("-w")
+        .arg("3")
+        .arg("-c")
+        .arg("3")
+        .assert()
+        .success()
+        .stdout("delta delta bravo\nbravo charlie alpha\ncharlie delta delta\n")
+        .stderr("Entropy: 6.00 bits\n");
+}
+
+#[test]
+fn passphrase_with_nonexistent_wordlist() {
+    utils::command::command()
+        .arg("passphrase")
+        .arg("--wordlist")
+        .arg("non_existent.txt")
+        .assert()
+        .failure()
+        .code(66)
+        .stderr(predicate::str::contains("could not read non_existent.txt"));
+}
+
+#[test]
+fn passphrase_with_insecure_rng() {
+    utils::command::command()
+        .arg("-r")
+        .arg("splitmix64")
+        .arg("passphrase")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "splitmix64 is not a cryptographically secure RNG",
+        ));
+}
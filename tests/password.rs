@@ -0,0 +1,120 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod utils;
+
+use predicates::prelude::predicate;
+
+#[test]
+fn password() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("password")
+        .arg("-c")
+        .arg("2")
+        .assert()
+        .success()
+        .stdout("69tCHlG40fq9svRd\nLlGYoTAv8rOVvyKi\n")
+        .stderr("Entropy: 95.18 bits\n");
+}
+
+#[test]
+fn password_follows_policy() {
+    let output = utils::command::command()
+        .arg("password")
+        .arg("-l")
+        .arg("8")
+        .arg("--lower=2")
+        .arg("--digit=3")
+        .arg("--exclude-ambiguous")
+        .arg("-c")
+        .arg("100")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    for password in String::from_utf8(output.stdout).unwrap().lines() {
+        assert_eq!(password.len(), 8);
+        assert!(password.chars().filter(char::is_ascii_lowercase).count() >= 2);
+        assert!(password.chars().filter(char::is_ascii_digit).count() >= 3);
+        assert!(
+            password
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+        );
+        assert!(!password.contains(['0', '1', 'l']));
+    }
+}
+
+#[test]
+fn password_with_custom() {
+    let output = utils::command::command()
+        .arg("password")
+        .arg("-l")
+        .arg("4")
+        .arg("--custom")
+        .arg("xyzzy")
+        .arg("-c")
+        .arg("100")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(output.stderr, b"Entropy: 6.34 bits\n");
+    for password in String::from_utf8(output.stdout).unwrap().lines() {
+        assert_eq!(password.len(), 4);
+        assert!(password.chars().all(|c| "xyz".contains(c)));
+    }
+}
+
+#[test]
+fn password_with_overlapping_custom() {
+    utils::command::command()
+        .arg("password")
+        .arg("--digit")
+        .arg("--custom")
+        .arg("0123")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "custom characters overlap with the digit characters",
+        ));
+}
+
+#[test]
+fn password_with_too_restrictive_policy() {
+    utils::command::command()
+        .arg("password")
+        .arg("-l")
+        .arg("8")
+        .arg("--lower=4")
+        .arg("--digit=5")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "the password length 8 is less than the number of required characters 9",
+        ));
+}
+
+#[test]
+fn password_with_insecure_rng() {
+    utils::command::command()
+        .arg("-r")
+        .arg("splitmix64")
+        .arg("password")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "splitmix64 is not a cryptographically secure RNG; use --allow-insecure to use it anyway",
+        ));
+    utils::command::command()
+        .arg("-r")
+        .arg("splitmix64")
+        .arg("password")
+        .arg("--allow-insecure")
+        .assert()
+        .success();
+}
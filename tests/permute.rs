@@ -0,0 +1,141 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod utils;
+
+use predicates::prelude::predicate;
+
+#[test]
+fn permute() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("permute")
+        .arg("10")
+        .assert()
+        .success()
+        .stdout("4\n1\n5\n7\n0\n6\n3\n2\n8\n9\n");
+}
+
+#[test]
+fn permute_with_head_count() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("permute")
+        .arg("-n")
+        .arg("3")
+        .arg("10")
+        .assert()
+        .success()
+        .stdout("4\n1\n5\n");
+}
+
+#[test]
+fn combination() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("permute")
+        .arg("-n")
+        .arg("3")
+        .arg("--combination")
+        .arg("10")
+        .assert()
+        .success()
+        .stdout("1\n4\n5\n");
+}
+
+#[test]
+fn permute_by_index() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("permute")
+        .arg("-i")
+        .arg("0")
+        .arg("-i")
+        .arg("9")
+        .arg("10")
+        .assert()
+        .success()
+        .stdout("4\n9\n");
+}
+
+#[test]
+fn permute_large_range() {
+    let output = utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("permute")
+        .arg("-n")
+        .arg("1000")
+        .arg("2000000")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let elements = String::from_utf8(output.stdout).unwrap();
+    let elements = elements
+        .lines()
+        .map(|n| n.parse::<u32>().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(elements.len(), 1000);
+    assert!(elements.iter().all(|&n| n < 2_000_000));
+
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("permute")
+        .arg("-i")
+        .arg("999")
+        .arg("2000000")
+        .assert()
+        .success()
+        .stdout(format!("{}\n", elements[999]));
+}
+
+#[test]
+fn permute_with_out_of_range_index() {
+    utils::command::command()
+        .arg("permute")
+        .arg("-i")
+        .arg("10")
+        .arg("10")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "index 10 is out of range for 10 elements",
+        ));
+}
+
+#[test]
+fn too_large_combination() {
+    utils::command::command()
+        .arg("permute")
+        .arg("-n")
+        .arg("1048577")
+        .arg("--combination")
+        .arg("1000000000")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "--combination can output at most 1048576 elements",
+        ));
+}
+
+#[test]
+fn combination_without_head_count() {
+    utils::command::command()
+        .arg("permute")
+        .arg("--combination")
+        .arg("10")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "the following required arguments were not provided",
+        ));
+}
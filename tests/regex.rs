@@ -0,0 +1,84 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod utils;
+
+use predicates::prelude::predicate;
+
+#[test]
+fn regex() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("--regex")
+        .arg(r"[A-Z]{3}-\d{4}")
+        .arg("-c")
+        .arg("3")
+        .assert()
+        .success()
+        .stdout("ZIL-1598\nGZH-6061\nVGT-3926\n");
+}
+
+#[test]
+fn regex_with_alternation() {
+    let output = utils::command::command()
+        .arg("--regex")
+        .arg("(?:foo|bar)-[ぁ-ゖ]")
+        .arg("-c")
+        .arg("100")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.lines().count(), 100);
+    for line in stdout.lines() {
+        let (word, kana) = line.split_once('-').unwrap();
+        assert!(["foo", "bar"].contains(&word));
+        assert!(('ぁ'..='ゖ').contains(&kana.chars().next().unwrap()));
+    }
+}
+
+#[test]
+fn max_repeat() {
+    let output = utils::command::command()
+        .arg("--regex")
+        .arg("a+")
+        .arg("--max-repeat")
+        .arg("3")
+        .arg("-c")
+        .arg("100")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.lines().all(|line| (1..=3).contains(&line.len())));
+    assert!(stdout.lines().any(|line| line.len() == 3));
+}
+
+#[test]
+fn invalid_regex() {
+    utils::command::command()
+        .arg("--regex")
+        .arg("[a-")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "character class should be closed by `]`",
+        ));
+}
+
+#[test]
+fn regex_conflicts_with_length() {
+    utils::command::command()
+        .arg("--regex")
+        .arg("a")
+        .arg("8")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "the argument '--regex <PATTERN>' cannot be used with '[BYTES]'",
+        ));
+}
@@ -30,6 +30,7 @@ fn rng() {
     }
 }
 
+#[allow(clippy::too_many_lines)]
 #[test]
 fn rng_with_seed() {
     {
@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod utils;
+
+use predicates::prelude::predicate;
+
+fn input(len: usize) -> String {
+    let mut input = (1..=len)
+        .map(|i| i.to_string())
+        .collect::<Vec<_>>()
+        .join("\n");
+    input.push('\n');
+    input
+}
+
+#[test]
+fn sample() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("sample")
+        .arg("-n")
+        .arg("5")
+        .write_stdin(input(1000))
+        .assert()
+        .success()
+        .stdout("92\n145\n195\n417\n471\n");
+}
+
+#[test]
+fn sample_from_short_input() {
+    utils::command::command()
+        .arg("sample")
+        .arg("-n")
+        .arg("5")
+        .write_stdin(input(3))
+        .assert()
+        .success()
+        .stdout("1\n2\n3\n");
+}
+
+#[test]
+fn weighted_sample() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("sample")
+        .arg("-n")
+        .arg("2")
+        .arg("-w")
+        .arg("2")
+        .arg("-F")
+        .arg(",")
+        .write_stdin("a,1\nb,0\nc,5\n")
+        .assert()
+        .success()
+        .stdout("a,1\nc,5\n");
+}
+
+#[test]
+fn sample_with_invalid_weight() {
+    utils::command::command()
+        .arg("sample")
+        .arg("-n")
+        .arg("2")
+        .arg("-w")
+        .arg("2")
+        .write_stdin("a 1\nb x\n")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains("invalid record 2"))
+        .stderr(predicate::str::contains("invalid weight `x`"));
+}
+
+#[test]
+fn field_separator_requires_weight_field() {
+    utils::command::command()
+        .arg("sample")
+        .arg("-n")
+        .arg("2")
+        .arg("-F")
+        .arg(",")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "the following required arguments were not provided",
+        ));
+}
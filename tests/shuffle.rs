@@ -0,0 +1,76 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod utils;
+
+use predicates::prelude::predicate;
+
+#[test]
+fn shuffle() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("shuffle")
+        .write_stdin("1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n")
+        .assert()
+        .success()
+        .stdout("10\n1\n5\n7\n8\n6\n9\n2\n3\n4\n");
+}
+
+#[test]
+fn shuffle_with_head_count() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("shuffle")
+        .arg("-n")
+        .arg("3")
+        .write_stdin("1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n")
+        .assert()
+        .success()
+        .stdout("10\n1\n5\n");
+}
+
+#[test]
+fn shuffle_null_delimited_records() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("-0")
+        .arg("shuffle")
+        .write_stdin("a\x00b\x00c\x00")
+        .assert()
+        .success()
+        .stdout("c\x00a\x00b\x00");
+}
+
+#[test]
+fn shuffle_file_with_small_buffer() {
+    let output = utils::command::command()
+        .arg("shuffle")
+        .arg("-S")
+        .arg("64B")
+        .arg("data/wordlist.txt")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let input = std::fs::read_to_string("tests/data/wordlist.txt").unwrap();
+    let mut expected = input.lines().collect::<Vec<_>>();
+    expected.sort_unstable();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let mut lines = stdout.lines().collect::<Vec<_>>();
+    lines.sort_unstable();
+    assert_eq!(lines, expected);
+}
+
+#[test]
+fn shuffle_non_existent_file() {
+    utils::command::command()
+        .arg("shuffle")
+        .arg("non_existent.txt")
+        .assert()
+        .failure()
+        .code(66)
+        .stderr(predicate::str::contains("could not open non_existent.txt"));
+}
@@ -0,0 +1,56 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(clippy::literal_string_with_formatting_args)]
+
+mod utils;
+
+use predicates::prelude::predicate;
+
+#[test]
+fn template() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("--template")
+        .arg("key_live_{base62:24}")
+        .arg("-c")
+        .arg("2")
+        .assert()
+        .success()
+        .stdout("key_live_wzJSXBWuq5GzILh3bBWoEjQL\nkey_live_yHelLOa87zYoqcHRdtdNvWLZ\n");
+}
+
+#[cfg(feature = "hex")]
+#[test]
+fn template_with_uuid_and_hex() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("--template")
+        .arg("{uuid}-{hex:8} {{x}} {int:1..6}")
+        .assert()
+        .success()
+        .stdout("d17f36ca-3554-47f2-8b74-3534ef2c09ff-b080241c {x} 4\n");
+}
+
+#[test]
+fn invalid_template() {
+    utils::command::command()
+        .arg("--template")
+        .arg("{foo}")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("unknown placeholder `foo`"));
+    utils::command::command()
+        .arg("--template")
+        .arg("{base62:24")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "placeholder should be closed by `}`",
+        ));
+}
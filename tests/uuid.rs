@@ -0,0 +1,149 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod utils;
+
+use predicates::prelude::predicate;
+
+#[test]
+fn uuid_v4() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("uuid")
+        .arg("-c")
+        .arg("2")
+        .assert()
+        .success()
+        .stdout("d17f36ca-3554-47f2-8b74-3534ef2c09ff\nb080241c-114c-4552-be2d-7e911811e874\n");
+}
+
+#[test]
+fn uuid_v7() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("uuid")
+        .arg("-t")
+        .arg("v7")
+        .arg("--clock")
+        .arg("1700000000000")
+        .arg("--clock-step")
+        .arg("1")
+        .arg("-c")
+        .arg("3")
+        .assert()
+        .success()
+        .stdout(
+            "018bcfe5-6800-717f-b6ca-3554d7f24b74\n018bcfe5-6801-7f2c-89ff-b080241c114c\n\
+             018bcfe5-6802-7e2d-be91-1811e8742c6f\n",
+        );
+}
+
+#[test]
+fn uuid_v7_with_system_clock() {
+    let output = utils::command::command()
+        .arg("uuid")
+        .arg("-t")
+        .arg("v7")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let uuid = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(uuid.len(), 37);
+    assert_eq!(&uuid[14..15], "7");
+}
+
+#[test]
+fn uuid_v7_with_too_large_timestamp() {
+    utils::command::command()
+        .arg("uuid")
+        .arg("-t")
+        .arg("v7")
+        .arg("--clock")
+        .arg("281474976710656")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "timestamp 281474976710656 does not fit in 48 bits",
+        ));
+}
+
+#[test]
+fn uuid_v8() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("uuid")
+        .arg("-t")
+        .arg("v8")
+        .arg("--payload")
+        .arg("deadbeef")
+        .assert()
+        .success()
+        .stdout("deadbeef-d17f-86ca-b554-d7f24b743534\n");
+}
+
+#[test]
+fn uuid_with_payload_without_v8() {
+    utils::command::command()
+        .arg("uuid")
+        .arg("--payload")
+        .arg("deadbeef")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "--payload can only be used with version 8 UUIDs",
+        ));
+}
+
+#[test]
+fn uuid_with_style() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("uuid")
+        .arg("--style")
+        .arg("simple")
+        .assert()
+        .success()
+        .stdout("d17f36ca355447f28b743534ef2c09ff\n");
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("uuid")
+        .arg("--style")
+        .arg("urn")
+        .assert()
+        .success()
+        .stdout("urn:uuid:d17f36ca-3554-47f2-8b74-3534ef2c09ff\n");
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("uuid")
+        .arg("--style")
+        .arg("braced")
+        .assert()
+        .success()
+        .stdout("{d17f36ca-3554-47f2-8b74-3534ef2c09ff}\n");
+}
+
+#[test]
+fn clock_step_with_seed() {
+    utils::command::command()
+        .arg("-s")
+        .arg("256")
+        .arg("uuid")
+        .arg("-t")
+        .arg("v7")
+        .arg("--clock-step")
+        .arg("1")
+        .arg("-c")
+        .arg("2")
+        .assert()
+        .success()
+        .stdout("01941f29-7c00-717f-b6ca-3554d7f24b74\n01941f29-7c01-7f2c-89ff-b080241c114c\n");
+}